@@ -4,11 +4,17 @@
 
 use crate::service::FullClient;
 
-use runtime::{AccountId, Balance, BalancesCall, SystemCall};
+use pallet_bullposting::Direction;
+use runtime::{
+	genesis_config_presets::{
+		load_test_url, LOAD_TEST_ACCOUNTS, LOAD_TEST_POSTS, LOAD_TEST_UNVOTED_URL,
+	},
+	AccountId, Balance, BalancesCall, BullpostingCall, Nonce, Runtime, SystemCall,
+};
 use sc_cli::Result;
-use sc_client_api::BlockBackend;
+use sc_client_api::{BlockBackend, StorageProvider};
 use solochain_bullposting_runtime as runtime;
-use sp_core::{Encode, Pair};
+use sp_core::{storage::StorageKey, Decode, Encode, Pair};
 use sp_inherents::{InherentData, InherentDataProvider};
 use sp_keyring::Sr25519Keyring;
 use sp_runtime::{OpaqueExtrinsic, SaturatedConversion};
@@ -92,6 +98,181 @@ impl frame_benchmarking_cli::ExtrinsicBuilder for TransferKeepAliveBuilder {
 	}
 }
 
+/// Generates `Bullposting::TrySubmitPost` extrinsics for the benchmarks, each for a new URL.
+///
+/// Every extrinsic is signed by a different `//load/<index>` account at nonce 0, as the runtime rate limits
+/// submissions per account. Needs the `bullposting-load` chain, which funds those accounts.
+///
+/// Note: Should only be used for benchmarking.
+pub struct SubmitPostBuilder {
+	client: Arc<FullClient>,
+	bond: Balance,
+}
+
+impl SubmitPostBuilder {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<FullClient>, bond: Balance) -> Self {
+		Self { client, bond }
+	}
+}
+
+impl frame_benchmarking_cli::ExtrinsicBuilder for SubmitPostBuilder {
+	fn pallet(&self) -> &str {
+		"bullposting"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"try_submit_post"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		let acc = load_test_voter(nonce)?;
+		let extrinsic: OpaqueExtrinsic = create_benchmark_extrinsic(
+			self.client.as_ref(),
+			acc,
+			BullpostingCall::try_submit_post {
+				post_url: format!("https://example.com/benchmark/{nonce}").into_bytes(),
+				bond: self.bond,
+			}
+			.into(),
+			0,
+		)
+		.into();
+
+		Ok(extrinsic)
+	}
+}
+
+/// Generates `Bullposting::TrySubmitVote` extrinsics for the benchmarks, all on the load-test post that has no votes.
+///
+/// Every extrinsic is signed by a different `//load/<index>` account at nonce 0, as an account votes once per
+/// post. Needs the `bullposting-load` chain, which funds those accounts.
+///
+/// Note: Should only be used for benchmarking.
+pub struct SubmitVoteBuilder {
+	client: Arc<FullClient>,
+	vote_amount: Balance,
+}
+
+impl SubmitVoteBuilder {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<FullClient>, vote_amount: Balance) -> Self {
+		Self { client, vote_amount }
+	}
+}
+
+impl frame_benchmarking_cli::ExtrinsicBuilder for SubmitVoteBuilder {
+	fn pallet(&self) -> &str {
+		"bullposting"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"try_submit_vote"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		let acc = load_test_voter(nonce)?;
+		let extrinsic: OpaqueExtrinsic = create_benchmark_extrinsic(
+			self.client.as_ref(),
+			acc,
+			BullpostingCall::try_submit_vote {
+				post_url: LOAD_TEST_UNVOTED_URL.as_bytes().to_vec(),
+				vote_amount: self.vote_amount,
+				direction: Direction::Bullish,
+			}
+			.into(),
+			0,
+		)
+		.into();
+
+		Ok(extrinsic)
+	}
+}
+
+/// Generates `Bullposting::TryEndPost` extrinsics for the benchmarks, one for each load-test post.
+///
+/// The posts can only be ended once their appeal windows are over, so this needs a `bullposting-load` database
+/// past block 120 and at most [`LOAD_TEST_POSTS`] extrinsics per block. The extrinsics are signed by Bob from his
+/// nonce at the best block. Building fails if a post is missing, already ended or still appealable at the next
+/// block, rather than producing extrinsics that would fail.
+///
+/// Note: Should only be used for benchmarking.
+pub struct EndPostBuilder {
+	client: Arc<FullClient>,
+}
+
+impl EndPostBuilder {
+	/// Creates a new [`Self`] from the given client.
+	pub fn new(client: Arc<FullClient>) -> Self {
+		Self { client }
+	}
+}
+
+impl frame_benchmarking_cli::ExtrinsicBuilder for EndPostBuilder {
+	fn pallet(&self) -> &str {
+		"bullposting"
+	}
+
+	fn extrinsic(&self) -> &str {
+		"try_end_post"
+	}
+
+	fn build(&self, nonce: u32) -> std::result::Result<OpaqueExtrinsic, &'static str> {
+		if nonce >= LOAD_TEST_POSTS {
+			return Err("Only the load-test posts can be ended, use `--max-ext-per-block 10`")
+		}
+		let post_url = load_test_url(nonce);
+		let post: pallet_bullposting::Post<Runtime> = best_storage(
+			self.client.as_ref(),
+			pallet_bullposting::Posts::<Runtime>::hashed_key_for(sp_core::blake2_256(&post_url)),
+		)
+		.ok_or("Load-test post not found, use a `bullposting-load` database")?;
+		if post.is_ended() {
+			return Err("Load-test post already ended, use a fresh `bullposting-load` database")
+		}
+		if self.client.chain_info().best_number + 1 < post.appealable_until() {
+			return Err("Load-test post still appealable, import blocks past block 120")
+		}
+
+		let acc = Sr25519Keyring::Bob.pair();
+		let account: frame_system::AccountInfo<Nonce, <Runtime as frame_system::Config>::AccountData> =
+			best_storage(
+				self.client.as_ref(),
+				frame_system::Account::<Runtime>::hashed_key_for(AccountId::from(acc.public())),
+			)
+			.unwrap_or_default();
+		let extrinsic: OpaqueExtrinsic = create_benchmark_extrinsic(
+			self.client.as_ref(),
+			acc,
+			BullpostingCall::try_end_post { post_url }.into(),
+			account.nonce + nonce,
+		)
+		.into();
+
+		Ok(extrinsic)
+	}
+}
+
+/// Returns the key of the `index`th `//load/<index>` account that did not submit a load-test post.
+fn load_test_voter(index: u32) -> std::result::Result<sp_core::sr25519::Pair, &'static str> {
+	let index = index + LOAD_TEST_POSTS;
+	if index >= LOAD_TEST_ACCOUNTS {
+		return Err("Not enough load-test accounts, lower `--max-ext-per-block`")
+	}
+	Ok(sp_core::sr25519::Pair::from_string(&format!("//load/{index}"), None)
+		.expect("static values are valid; qed"))
+}
+
+/// Reads and decodes the value at the storage `key` of the best block.
+fn best_storage<T: Decode>(client: &FullClient, key: Vec<u8>) -> Option<T> {
+	let best_hash = client.chain_info().best_hash;
+	client
+		.storage(best_hash, &StorageKey(key))
+		.ok()
+		.flatten()
+		.and_then(|data| T::decode(&mut &data.0[..]).ok())
+}
+
 /// Create a transaction using the given `call`.
 ///
 /// Note: Should only be used for benchmarking.
@@ -1,9 +1,27 @@
-use sc_service::ChainType;
-use solochain_bullposting_runtime::WASM_BINARY;
+use sc_service::{ChainType, Properties};
+use solochain_bullposting_runtime::{
+	genesis_config_presets::{staging_config_genesis, LOAD_TEST_RUNTIME_PRESET},
+	configs::SS58Prefix,
+	AccountId, Balance, BlockNumber, BullpostingConfig, WASM_BINARY,
+};
+use sp_consensus_aura::sr25519::AuthorityId as AuraId;
+use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_runtime::DeserializeOwned;
+use std::path::Path;
 
 /// Specialized `ChainSpec`. This is a specialization of the general Substrate ChainSpec type.
 pub type ChainSpec = sc_service::GenericChainSpec;
 
+/// The token properties shown by wallets and block explorers.
+fn properties() -> Properties {
+	let mut properties = Properties::new();
+	properties.insert("tokenSymbol".into(), "BULL".into());
+	// `UNIT` is 10^12
+	properties.insert("tokenDecimals".into(), 12.into());
+	properties.insert("ss58Format".into(), SS58Prefix::get().into());
+	properties
+}
+
 pub fn development_chain_spec() -> Result<ChainSpec, String> {
 	Ok(ChainSpec::builder(
 		WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
@@ -11,6 +29,7 @@ pub fn development_chain_spec() -> Result<ChainSpec, String> {
 	)
 	.with_name("Development")
 	.with_id("dev")
+	.with_properties(properties())
 	.with_chain_type(ChainType::Development)
 	.with_genesis_config_preset_name(sp_genesis_builder::DEV_RUNTIME_PRESET)
 	.build())
@@ -23,7 +42,87 @@ pub fn local_chain_spec() -> Result<ChainSpec, String> {
 	)
 	.with_name("Local Testnet")
 	.with_id("local_testnet")
+	.with_properties(properties())
 	.with_chain_type(ChainType::Local)
 	.with_genesis_config_preset_name(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET)
 	.build())
 }
+
+pub fn load_test_chain_spec() -> Result<ChainSpec, String> {
+	Ok(ChainSpec::builder(
+		WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?,
+		None,
+	)
+	.with_name("Bullposting Load Test")
+	.with_id("bullposting_load")
+	.with_properties(properties())
+	.with_chain_type(ChainType::Development)
+	.with_genesis_config_preset_name(LOAD_TEST_RUNTIME_PRESET)
+	.build())
+}
+
+/// Reads the field `name` of the staging config `config`.
+fn staging_field<T: DeserializeOwned>(config: &serde_json::Value, name: &str) -> Result<T, String> {
+	let field = config.get(name).ok_or_else(|| format!("Staging config is missing `{name}`"))?;
+	serde_json::from_value(field.clone())
+		.map_err(|e| format!("Invalid `{name}` in staging config: {e}"))
+}
+
+/// The example staging config, built into the node so `--chain staging` works from any directory.
+const STAGING_CONFIG: &[u8] = include_bytes!("../res/staging.json");
+
+/// Returns a live chain spec whose genesis is read from the staging config at `path`, or from the
+/// built-in `node/res/staging.json` if there is no `path`.
+///
+/// The config is a JSON object with the Aura and Grandpa keys of the `authorities`, the
+/// `endowments` of accounts, the `sudo` key and the `bullposting` genesis `posts`, `votes` and
+/// `attesters`, with post URLs as strings. See `node/res/staging.json` for an example.
+pub fn staging_chain_spec(path: Option<&Path>) -> Result<ChainSpec, String> {
+	let (file, source) = match path {
+		Some(path) => (
+			std::fs::read(path).map_err(|e| format!("Error reading {}: {e}", path.display()))?,
+			path.display().to_string(),
+		),
+		None => (STAGING_CONFIG.to_vec(), "the built-in staging config".to_string()),
+	};
+	let config: serde_json::Value =
+		serde_json::from_slice(&file).map_err(|e| format!("Error parsing {source}: {e}"))?;
+
+	let authorities: Vec<(AuraId, GrandpaId)> = staging_field(&config, "authorities")?;
+	let endowments: Vec<(AccountId, Balance)> = staging_field(&config, "endowments")?;
+	let sudo: AccountId = staging_field(&config, "sudo")?;
+
+	let bullposting = match config.get("bullposting") {
+		Some(bullposting) => {
+			let posts: Vec<(AccountId, String, Balance, BlockNumber)> =
+				staging_field(bullposting, "posts")?;
+			let votes: Vec<(AccountId, String, Balance, pallet_bullposting::Direction)> =
+				staging_field(bullposting, "votes")?;
+			BullpostingConfig {
+				posts: posts
+					.into_iter()
+					.map(|(submitter, url, bond, voting_period)| {
+						(submitter, url.into_bytes(), bond, voting_period)
+					})
+					.collect(),
+				votes: votes
+					.into_iter()
+					.map(|(voter, url, amount, direction)| (voter, url.into_bytes(), amount, direction))
+					.collect(),
+				attesters: staging_field(bullposting, "attesters")?,
+			}
+		},
+		None => BullpostingConfig::default(),
+	};
+
+	Ok(ChainSpec::builder(
+		WASM_BINARY.ok_or_else(|| "Staging wasm not available".to_string())?,
+		None,
+	)
+	.with_name("Bullposting Staging")
+	.with_id("bullposting_staging")
+	.with_chain_type(ChainType::Live)
+	.with_properties(properties())
+	.with_genesis_config_patch(staging_config_genesis(authorities, endowments, sudo, bullposting))
+	.build())
+}
@@ -1,4 +1,35 @@
 use sc_cli::RunCmd;
+use std::str::FromStr;
+
+/// How blocks are sealed when the node runs with `--sealing`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sealing {
+	/// Seal a block as soon as a transaction enters the pool.
+	Instant,
+	/// Only seal blocks when requested through the `engine_createBlock` RPC.
+	Manual,
+	/// Seal a block every given number of milliseconds.
+	Interval(u64),
+}
+
+impl FromStr for Sealing {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"instant" => Ok(Sealing::Instant),
+			"manual" => Ok(Sealing::Manual),
+			millis => millis
+				.parse()
+				.ok()
+				.filter(|millis| *millis > 0)
+				.map(Sealing::Interval)
+				.ok_or_else(|| {
+					format!("expected `instant`, `manual` or a number of milliseconds, got `{s}`")
+				}),
+		}
+	}
+}
 
 #[derive(Debug, clap::Parser)]
 pub struct Cli {
@@ -7,6 +38,21 @@ pub struct Cli {
 
 	#[clap(flatten)]
 	pub run: RunCmd,
+
+	/// Seal blocks with manual seal instead of Aura and Grandpa, for development and tests.
+	///
+	/// `instant` seals a block for each transaction, `manual` only seals blocks on calls to the
+	/// `engine_createBlock` RPC, and a number of milliseconds seals a block at that interval. The
+	/// RPC is available in every mode, and sealed blocks are finalized straight away.
+	#[arg(long, value_name = "instant|manual|<ms>")]
+	pub sealing: Option<Sealing>,
+
+	/// Do not index post URLs in the offchain database.
+	///
+	/// The node enables offchain indexing by default for the bullposting offchain worker and the
+	/// `bullposting_urlOf` RPC. With this flag it is only enabled by `--enable-offchain-indexing`.
+	#[arg(long)]
+	pub no_offchain_indexing: bool,
 }
 
 #[derive(Debug, clap::Subcommand)]
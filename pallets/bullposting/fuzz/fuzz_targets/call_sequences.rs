@@ -0,0 +1,12 @@
+//! Runs arbitrary call sequences through the pallet's harness.
+//!
+//! `cargo +nightly fuzz run call_sequences` from `pallets/bullposting`.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pallet_bullposting::harness::{self, Action};
+
+fuzz_target!(|actions: Vec<Action>| {
+    harness::run(&actions);
+});
@@ -0,0 +1,79 @@
+//! RPC interface for the bullposting pallet.
+//!
+//! Only the hashes of post URLs are kept in runtime state. The pallet writes each URL to the
+//! node's offchain database through offchain indexing, which the methods here read from.
+
+use codec::Decode;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject},
+};
+use sp_core::{
+	offchain::{OffchainStorage, STORAGE_PREFIX},
+	H256,
+};
+
+#[rpc(client, server)]
+pub trait BullpostingApi {
+	/// The URL of the post with the given ID, if this node indexed it.
+	///
+	/// Posts are only indexed by nodes that imported them with offchain indexing enabled. URLs
+	/// that are not valid UTF-8 are returned with the invalid bytes replaced.
+	#[method(name = "bullposting_urlOf")]
+	fn url_of(&self, id: H256) -> RpcResult<Option<String>>;
+}
+
+/// Provides RPC methods to look up post URLs in the offchain database.
+pub struct Bullposting<S> {
+	/// The node's offchain database.
+	storage: S,
+}
+
+impl<S> Bullposting<S> {
+	/// Creates a new instance of the Bullposting Rpc helper.
+	pub fn new(storage: S) -> Self {
+		Self { storage }
+	}
+}
+
+impl<S> BullpostingApiServer for Bullposting<S>
+where
+	S: OffchainStorage + 'static,
+{
+	fn url_of(&self, id: H256) -> RpcResult<Option<String>> {
+		let Some(value) = self.storage.get(STORAGE_PREFIX, &pallet_bullposting::url_key(&id.0))
+		else {
+			return Ok(None)
+		};
+
+		let url = Vec::<u8>::decode(&mut &value[..]).map_err(|e| {
+			ErrorObject::owned(
+				ErrorCode::InternalError.code(),
+				"Unable to decode the indexed URL.",
+				Some(format!("{:?}", e)),
+			)
+		})?;
+
+		Ok(Some(String::from_utf8_lossy(&url).into_owned()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+	use sp_core::offchain::storage::InMemOffchainStorage;
+
+	#[test]
+	fn url_of_reads_indexed_urls() {
+		let url = b"https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".to_vec();
+		let id = sp_core::hashing::blake2_256(&url);
+		let mut storage = InMemOffchainStorage::default();
+		storage.set(STORAGE_PREFIX, &pallet_bullposting::url_key(&id), &url.encode());
+
+		let rpc = Bullposting::new(storage);
+		assert_eq!(rpc.url_of(id.into()).unwrap(), Some(String::from_utf8(url).unwrap()));
+		assert_eq!(rpc.url_of(H256::zero()).unwrap(), None);
+	}
+}
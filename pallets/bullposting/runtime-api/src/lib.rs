@@ -0,0 +1,29 @@
+//! Runtime API definition for the bullposting pallet.
+//!
+//! Lets the node and its RPCs query bullposting state that is not convenient to decode from raw
+//! storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// Queries the posts of the bullposting pallet by post ID, and its voters by account.
+	pub trait BullpostingApi<Attestation, Outcome, AccountId, VoterRecord> where
+		Attestation: Codec,
+		Outcome: Codec,
+		AccountId: Codec,
+		VoterRecord: Codec,
+	{
+		/// The offchain worker's attestation of the URL of a post, if it has been checked.
+		fn attestation(id: [u8; 32]) -> Option<Attestation>;
+
+		/// The outcome of an ended post, kept after it is resolved until the pallet's `OutcomeRetention` has passed.
+		#[api_version(2)]
+		fn outcome(id: [u8; 32]) -> Option<Outcome>;
+
+		/// How often the votes of an account on resolved posts sided with the verdict.
+		#[api_version(3)]
+		fn voter_record(who: AccountId) -> VoterRecord;
+	}
+}
@@ -6,27 +6,58 @@ use super::*;
 use crate::Pallet as BullPosting;
 use frame_benchmarking::v2::*;
 use frame_system::RawOrigin;
-use frame_support::traits::{Get, fungible::{Inspect, Mutate}};
+use frame_support::traits::{EnsureOrigin, Get, fungible::{Inspect, Mutate}};
 use frame_support::sp_runtime::*;
-use crate::benchmarking::traits::{Zero, One};
+use crate::benchmarking::traits::{Zero, One, IdentifyAccount};
+use crate::migrations::{self, v1::{v0, LazyMigrationV0ToV1}, v2::{v1, LazyMigrationV1ToV2}, v3::{v2, LazyMigrationV2ToV3}, v4::LazyMigrationV3ToV4, v5::LazyMigrationV4ToV5};
+use frame_support::{migrations::SteppedMigration, traits::{GetStorageVersion, StorageVersion}, weights::WeightMeter};
+use frame_support::{traits::UnfilteredDispatchable, unsigned::ValidateUnsigned};
+use frame_support::sp_runtime::transaction_validity::TransactionSource;
+use frame_system::offchain::{AppCrypto, SignedPayload};
 
 const SEED: u32 = 0;
 const MAX_URL: usize = 2000;
+// Voters on a post are uncapped, this is the largest number of voters `try_resolve_voting` is benchmarked with.
+// Keeping a voter's freeze at their largest vote scans their `VoteAmounts`, so `try_update_vote` and
+// `try_resolve_voting` are also benchmarked by the number of votes each voter has open, up to `MaxVotesPerAccount`.
 const MAX_VOTERS: u32 = 10000;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+// Opens `count` more small votes in the account's `VoteAmounts`, ahead of the vote the benchmark is about,
+// so finding that vote again scans every one of them
+fn fill_vote_amounts<T: Config>(who: &T::AccountId, count: u32) -> Result<(), BenchmarkError> {
+	let amount = <T as pallet::Config>::NativeBalance::minimum_balance();
+	VoteAmounts::<T>::try_mutate(who, |amounts| {
+		for _ in 0..count {
+			amounts.try_push(amount).map_err(|_| BenchmarkError::Weightless)?;
+		}
+		Ok(())
+	})
+}
+
+// A successful origin of `O`, such as the `SubmitOrigin` or `VoteOrigin`, and the account it dispatches as
+fn successful_origin<T: Config, O: EnsureOrigin<T::RuntimeOrigin, Success = T::AccountId>>(
+) -> Result<(T::RuntimeOrigin, T::AccountId), BenchmarkError> {
+	let origin = O::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+	let who = O::ensure_origin(origin.clone()).map_err(|_| BenchmarkError::Weightless)?;
+	Ok((origin, who))
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
 
     #[benchmark]
-    fn try_submit_post<T: Config>() -> Result<(), BenchmarkError> {
-		let post: Vec<u8> = [255u8; MAX_URL].to_vec();
+    fn try_submit_post<T: Config>(
+		u: Linear<1, { T::MaxUrlLength::get() }>
+	) -> Result<(), BenchmarkError> {
+		let post: Vec<u8> = [255u8].repeat(u as usize);
 		let post_id: [u8; 32] = sp_io::hashing::blake2_256(&post);
-		let caller: T::AccountId = whitelisted_caller();
+		let (origin, caller) = successful_origin::<T, T::SubmitOrigin>()?;
+		whitelist_account!(caller);
 		let balance = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(u32::MAX.into());
 		let bond = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(1000u32.into());
 
@@ -35,7 +66,7 @@ mod benchmarks {
 		<T as pallet::Config>::NativeBalance::set_balance(&caller, balance);
 
 		#[extrinsic_call]
-		try_submit_post(RawOrigin::Signed(caller.clone()), post, bond.clone());
+		try_submit_post(origin as T::RuntimeOrigin, post, bond.clone());
 
 		let voting_until = frame_system::Pallet::<T>::block_number() +
             T::VotingPeriod::get();
@@ -50,11 +81,13 @@ mod benchmarks {
 	}
 
     #[benchmark]
-    fn try_submit_vote<T: Config>() -> Result<(), BenchmarkError> {
-		let post: Vec<u8> = [255u8; MAX_URL].to_vec();
+    fn try_submit_vote<T: Config>(
+		u: Linear<1, { T::MaxUrlLength::get() }>
+	) -> Result<(), BenchmarkError> {
+		let post: Vec<u8> = [255u8].repeat(u as usize);
 		let post_id: [u8; 32] = sp_io::hashing::blake2_256(&post);
 		let alice: T::AccountId = account("Alice", 0, SEED);
-		let bob: T::AccountId = account("Bob", 0, SEED);
+		let (origin, bob) = successful_origin::<T, T::VoteOrigin>()?;
 		let balance = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(u32::MAX.into());
 		let bond = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(1000u32.into());
 		let vote_amount = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(5000u32.into());
@@ -67,7 +100,7 @@ mod benchmarks {
 		BullPosting::<T>::try_submit_post(RawOrigin::Signed(alice.clone()).into(), post.clone(), bond)?;
 
         #[extrinsic_call]
-		try_submit_vote(RawOrigin::Signed(bob.clone()), post, vote_amount, Direction::Bullish);
+		try_submit_vote(origin as T::RuntimeOrigin, post, vote_amount, Direction::Bullish);
 
 		assert_last_event::<T>(Event::VoteSubmitted {
 			id: post_id,
@@ -79,11 +112,14 @@ mod benchmarks {
 	}
 
     #[benchmark]
-    fn try_update_vote<T: Config>() -> Result<(), BenchmarkError> {
-		let post: Vec<u8> = [255u8; MAX_URL].to_vec();
+    fn try_update_vote<T: Config>(
+		u: Linear<1, { T::MaxUrlLength::get() }>,
+		v: Linear<1, { T::MaxVotesPerAccount::get() }>
+	) -> Result<(), BenchmarkError> {
+		let post: Vec<u8> = [255u8].repeat(u as usize);
 		let post_id: [u8; 32] = sp_io::hashing::blake2_256(&post);
 		let alice: T::AccountId = account("Alice", 0, SEED);
-		let bob: T::AccountId = account("Bob", 0, SEED);
+		let (origin, bob) = successful_origin::<T, T::VoteOrigin>()?;
 		let balance = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(u32::MAX.into());
 		let bond = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(1000u32.into());
 		let vote_amount = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(5000u32.into());
@@ -95,10 +131,11 @@ mod benchmarks {
 		<T as pallet::Config>::NativeBalance::set_balance(&bob, balance);
 
 		BullPosting::<T>::try_submit_post(RawOrigin::Signed(alice.clone()).into(), post.clone(), bond)?;
-		BullPosting::<T>::try_submit_vote(RawOrigin::Signed(bob.clone()).into(), post.clone(), vote_amount, Direction::Bullish)?;
+		fill_vote_amounts::<T>(&bob, v - 1)?;
+		BullPosting::<T>::try_submit_vote(origin.clone(), post.clone(), vote_amount, Direction::Bullish)?;
 
         #[extrinsic_call]
-		try_update_vote(RawOrigin::Signed(bob.clone()), post, new_vote_amount, Direction::Bearish);
+		try_update_vote(origin as T::RuntimeOrigin, post, new_vote_amount, Direction::Bearish);
 
 		assert_last_event::<T>(Event::VoteUpdated {
 			id: post_id,
@@ -110,8 +147,10 @@ mod benchmarks {
 	}
 
     #[benchmark]
-    fn try_end_post<T: Config>() -> Result<(), BenchmarkError> {
-		let post: Vec<u8> = [250u8; MAX_URL].to_vec();
+    fn try_end_post<T: Config>(
+		u: Linear<1, { T::MaxUrlLength::get() }>
+	) -> Result<(), BenchmarkError> {
+		let post: Vec<u8> = [250u8].repeat(u as usize);
 		let post_id: [u8; 32] = sp_io::hashing::blake2_256(&post);
 		let alice: T::AccountId = account("Alice", 0, SEED);
 		let bob: T::AccountId = account("Bob", 0, SEED);
@@ -127,10 +166,21 @@ mod benchmarks {
 		BullPosting::<T>::try_submit_post(RawOrigin::Signed(alice.clone()).into(), post.clone(), bond)?;
 		BullPosting::<T>::try_submit_vote(RawOrigin::Signed(bob.clone()).into(), post.clone(), vote_amount, Direction::Bullish)?;
 
-		let new_block_num = frame_system::Pallet::<T>::block_number() +
-		T::VotingPeriod::get() + One::one();
+		// Appeal as many times as possible so every appeal bond is settled, escalating the bond each time
+		let mut appeal_bond = bond;
+		for i in 0..T::MaxAppeals::get() {
+			let appellant: T::AccountId = account("appellant", i, SEED);
+			appeal_bond = appeal_bond.saturating_mul(T::AppealBondCoefficient::get().into()) / 100u32.into();
+			<T as pallet::Config>::NativeBalance::set_balance(&appellant, balance);
 
-		frame_system::Pallet::<T>::set_block_number(new_block_num);
+			let voting_until = Posts::<T>::get(post_id).ok_or(BenchmarkError::Weightless)?.voting_until;
+			frame_system::Pallet::<T>::set_block_number(voting_until);
+			BullPosting::<T>::appeal_post(RawOrigin::Signed(appellant).into(), post.clone(), appeal_bond)?;
+		}
+
+		let appealable_until = Posts::<T>::get(post_id).ok_or(BenchmarkError::Weightless)?.appealable_until();
+
+		frame_system::Pallet::<T>::set_block_number(appealable_until);
 
         #[extrinsic_call]
 		try_end_post(RawOrigin::Signed(bob.clone()), post);
@@ -148,7 +198,8 @@ mod benchmarks {
 
 	#[benchmark]
     fn try_resolve_voting<T: Config>(
-		x: Linear<1, MAX_VOTERS>
+		x: Linear<1, MAX_VOTERS>,
+		v: Linear<1, { T::MaxVotesPerAccount::get() }>
 	) -> Result<(), BenchmarkError> {
 		let post: Vec<u8> = [255u8; MAX_URL].to_vec();
 		let post_id: [u8; 32] = sp_io::hashing::blake2_256(&post);
@@ -169,11 +220,12 @@ mod benchmarks {
 		for i in 0..x {
 			let acc: T::AccountId = account("filler", i, SEED);
 			<T as pallet::Config>::NativeBalance::set_balance(&acc, balance);
+			fill_vote_amounts::<T>(&acc, v - 1)?;
 			BullPosting::<T>::try_submit_vote(RawOrigin::Signed(acc).into(), post.clone(), vote_amount, Direction::Bullish)?;
 		}
 
 		let new_block_num = frame_system::Pallet::<T>::block_number() +
-		T::VotingPeriod::get() + One::one();
+		T::VotingPeriod::get() + T::AppealPeriod::get() + One::one();
 
 		frame_system::Pallet::<T>::set_block_number(new_block_num);
 
@@ -193,5 +245,463 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+    fn report_post<T: Config>() -> Result<(), BenchmarkError> {
+		let post: Vec<u8> = [255u8; MAX_URL].to_vec();
+		let post_id: [u8; 32] = sp_io::hashing::blake2_256(&post);
+		let alice: T::AccountId = account("Alice", 0, SEED);
+		let bob: T::AccountId = account("Bob", 0, SEED);
+		let balance = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(u32::MAX.into());
+		let bond = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(1000u32.into());
+
+		frame_system::Pallet::<T>::set_block_number(One::one());
+
+		<T as pallet::Config>::NativeBalance::set_balance(&alice, balance);
+		<T as pallet::Config>::NativeBalance::set_balance(&bob, balance);
+
+		BullPosting::<T>::try_submit_post(RawOrigin::Signed(alice.clone()).into(), post.clone(), bond)?;
+
+        #[extrinsic_call]
+		report_post(RawOrigin::Signed(bob.clone()), post);
+
+		assert_last_event::<T>(Event::PostReported {
+			id: post_id,
+			reporter: bob,
+			deposit: T::ReportDeposit::get().into(),
+		}.into());
+		Ok(())
+	}
+
+	#[benchmark]
+    fn force_remove_post<T: Config>() -> Result<(), BenchmarkError> {
+		let post: Vec<u8> = [255u8; MAX_URL].to_vec();
+		let post_id: [u8; 32] = sp_io::hashing::blake2_256(&post);
+		let alice: T::AccountId = account("Alice", 0, SEED);
+		let bob: T::AccountId = account("Bob", 0, SEED);
+		let balance = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(u32::MAX.into());
+		let bond = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(1000u32.into());
+		let origin = T::ModeratorOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		frame_system::Pallet::<T>::set_block_number(One::one());
+
+		<T as pallet::Config>::NativeBalance::set_balance(&alice, balance);
+		<T as pallet::Config>::NativeBalance::set_balance(&bob, balance);
+
+		BullPosting::<T>::try_submit_post(RawOrigin::Signed(alice.clone()).into(), post.clone(), bond)?;
+		BullPosting::<T>::report_post(RawOrigin::Signed(bob).into(), post.clone())?;
+
+		// Upholding the reports slashes the bond, which is the more expensive decision
+        #[extrinsic_call]
+		force_remove_post(origin as T::RuntimeOrigin, post, ModerationDecision::Upheld);
+
+		assert_last_event::<T>(Event::PostRemoved {
+			id: post_id,
+			submitter: alice,
+			decision: ModerationDecision::Upheld,
+			slashed: bond,
+		}.into());
+		Ok(())
+	}
+
+	#[benchmark]
+    fn appeal_post<T: Config>() -> Result<(), BenchmarkError> {
+		let post: Vec<u8> = [255u8; MAX_URL].to_vec();
+		let post_id: [u8; 32] = sp_io::hashing::blake2_256(&post);
+		let alice: T::AccountId = account("Alice", 0, SEED);
+		let bob: T::AccountId = account("Bob", 0, SEED);
+		let balance = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(u32::MAX.into());
+		let bond = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(1000u32.into());
+		let appeal_bond = bond.saturating_mul(T::AppealBondCoefficient::get().into()) / 100u32.into();
+
+		frame_system::Pallet::<T>::set_block_number(One::one());
+
+		<T as pallet::Config>::NativeBalance::set_balance(&alice, balance);
+		<T as pallet::Config>::NativeBalance::set_balance(&bob, balance);
+
+		BullPosting::<T>::try_submit_post(RawOrigin::Signed(alice.clone()).into(), post.clone(), bond)?;
+
+		let new_block_num = frame_system::Pallet::<T>::block_number() + T::VotingPeriod::get();
+
+		frame_system::Pallet::<T>::set_block_number(new_block_num);
+
+        #[extrinsic_call]
+		appeal_post(RawOrigin::Signed(bob.clone()), post, appeal_bond);
+
+		assert_last_event::<T>(Event::PostAppealed {
+			id: post_id,
+			appellant: bob,
+			bond: appeal_bond,
+			round: 1,
+			voting_until: new_block_num + T::AppealVotingPeriod::get(),
+		}.into());
+		Ok(())
+	}
+
+	#[benchmark]
+    fn try_submit_votes<T: Config>(
+		x: Linear<1, { T::MaxBatchSize::get() }>,
+		u: Linear<1, { T::MaxBatchSize::get() * MAX_URL as u32 }>
+	) -> Result<(), BenchmarkError> {
+		let (origin, bob) = successful_origin::<T, T::VoteOrigin>()?;
+		let balance = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(u32::MAX.into());
+		let bond = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(1000u32.into());
+		let vote_amount = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(5000u32.into());
+
+		frame_system::Pallet::<T>::set_block_number(One::one());
+
+		<T as pallet::Config>::NativeBalance::set_balance(&bob, balance);
+
+		// The URLs share the `u` bytes evenly, each long enough to tell the posts apart
+		let url_len = (u / x).clamp(4, MAX_URL as u32) as usize;
+
+		// Submit a different post for each vote in the batch, from different accounts to stay within the rate limits
+		let mut votes = Vec::new();
+		for i in 0..x {
+			let submitter: T::AccountId = account("submitter", i, SEED);
+			let mut post: Vec<u8> = vec![255u8; url_len];
+			post[..4].copy_from_slice(&i.to_le_bytes());
+			<T as pallet::Config>::NativeBalance::set_balance(&submitter, balance);
+			BullPosting::<T>::try_submit_post(RawOrigin::Signed(submitter).into(), post.clone(), bond)?;
+			votes.push((post, vote_amount, Direction::Bullish));
+		}
+
+		let last_id: [u8; 32] = sp_io::hashing::blake2_256(&votes[votes.len() - 1].0);
+
+        #[extrinsic_call]
+		try_submit_votes(origin as T::RuntimeOrigin, votes);
+
+		assert_last_event::<T>(Event::VoteSubmitted {
+			id: last_id,
+			voter: bob,
+			vote_amount,
+			direction: Direction::Bullish,
+		}.into());
+		Ok(())
+	}
+
+	#[benchmark]
+    fn try_end_posts<T: Config>(
+		x: Linear<1, { T::MaxBatchSize::get() }>,
+		u: Linear<1, { T::MaxBatchSize::get() * MAX_URL as u32 }>
+	) -> Result<(), BenchmarkError> {
+		let bob: T::AccountId = account("Bob", 0, SEED);
+		let balance = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(u32::MAX.into());
+		let bond = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(1000u32.into());
+		let vote_amount = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(5000u32.into());
+
+		frame_system::Pallet::<T>::set_block_number(One::one());
+
+		<T as pallet::Config>::NativeBalance::set_balance(&bob, balance);
+
+		// Submit and vote Bullish on a different post for each item, so every post rewards the submitter.
+		// Each post has its own submitter to stay within the rate limits
+		let url_len = (u / x).clamp(4, MAX_URL as u32) as usize;
+		let mut posts = Vec::new();
+		for i in 0..x {
+			let submitter: T::AccountId = account("submitter", i, SEED);
+			let mut post: Vec<u8> = vec![250u8; url_len];
+			post[..4].copy_from_slice(&i.to_le_bytes());
+			<T as pallet::Config>::NativeBalance::set_balance(&submitter, balance);
+			BullPosting::<T>::try_submit_post(RawOrigin::Signed(submitter).into(), post.clone(), bond)?;
+			BullPosting::<T>::try_submit_vote(RawOrigin::Signed(bob.clone()).into(), post.clone(), vote_amount, Direction::Bullish)?;
+			posts.push(post);
+		}
+
+		let last_id: [u8; 32] = sp_io::hashing::blake2_256(&posts[posts.len() - 1]);
+		let last_submitter: T::AccountId = account("submitter", x - 1, SEED);
+
+		let new_block_num = frame_system::Pallet::<T>::block_number() +
+		T::VotingPeriod::get() + T::AppealPeriod::get() + One::one();
+
+		frame_system::Pallet::<T>::set_block_number(new_block_num);
+
+        #[extrinsic_call]
+		try_end_posts(RawOrigin::Signed(bob.clone()), posts);
+
+		assert_last_event::<T>(Event::PostEnded {
+			id: last_id,
+			submitter: last_submitter,
+			result: Direction::Bullish,
+			rewarded: bond,
+			slashed: Zero::zero(),
+		}.into());
+		Ok(())
+	}
+
+	#[benchmark]
+    fn attest_url<T: Config>() -> Result<(), BenchmarkError> {
+		let post: Vec<u8> = [255u8; MAX_URL].to_vec();
+		let post_id: [u8; 32] = sp_io::hashing::blake2_256(&post);
+		let alice: T::AccountId = account("Alice", 0, SEED);
+		let balance = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(u32::MAX.into());
+		let bond = <T as pallet::Config>::NativeBalance::minimum_balance().saturating_add(1000u32.into());
+		let content_hash = [1u8; 32];
+
+		frame_system::Pallet::<T>::set_block_number(One::one());
+
+		<T as pallet::Config>::NativeBalance::set_balance(&alice, balance);
+
+		BullPosting::<T>::try_submit_post(RawOrigin::Signed(alice).into(), post, bond)?;
+
+		// The last of the most attesters signs the attestation, so checking it reads the longest list
+		let key = <<T::AuthorityId as AppCrypto<T::Public, T::Signature>>::RuntimeAppPublic as RuntimeAppPublic>::generate_pair(None);
+		let public: T::Public = <T::AuthorityId as AppCrypto<T::Public, T::Signature>>::GenericPublic::from(key).into();
+		let mut attesters: Vec<T::AccountId> = (1..T::MaxAttesters::get()).map(|i| account("attester", i, SEED)).collect();
+		attesters.push(public.clone().into_account());
+		Attesters::<T>::put(BoundedVec::truncate_from(attesters));
+
+		// The longest title the attestation can store
+		let title = BoundedVec::truncate_from([b'a'].repeat(T::MaxTitleLength::get() as usize));
+		let attestation = AttestationPayload::<T> { id: post_id, status: 200, content_hash, title, public };
+		let signature = attestation.sign::<T::AuthorityId>().ok_or(BenchmarkError::Stop("cannot sign the attestation"))?;
+		let call = Call::<T>::attest_url { attestation, signature };
+
+		#[block]
+		{
+			// The signature is checked by `validate_unsigned` when the attestation is included, which only accepts attestations
+			// while `AttestUrls` is enabled
+			if T::AttestUrls::get() {
+				BullPosting::<T>::validate_unsigned(TransactionSource::InBlock, &call)
+					.map_err(|_| BenchmarkError::Stop("invalid attestation"))?;
+			}
+			call.dispatch_bypass_filter(RawOrigin::None.into())?;
+		}
+
+		assert_last_event::<T>(Event::UrlAttested {
+			id: post_id,
+			status: 200,
+			content_hash,
+		}.into());
+		Ok(())
+	}
+
+	#[benchmark]
+    fn pause<T: Config>() -> Result<(), BenchmarkError> {
+		let origin = T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		frame_system::Pallet::<T>::set_block_number(One::one());
+
+        #[extrinsic_call]
+		pause(origin as T::RuntimeOrigin, PauseScope::All);
+
+		assert_eq!(PalletStatus::<T>::get(), PauseStatus { submissions: true, voting: true, ending: true });
+		assert_last_event::<T>(Event::Paused { scope: PauseScope::All }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+    fn unpause<T: Config>() -> Result<(), BenchmarkError> {
+		let origin = T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		frame_system::Pallet::<T>::set_block_number(One::one());
+
+		PalletStatus::<T>::put(PauseStatus { submissions: true, voting: true, ending: true });
+
+        #[extrinsic_call]
+		unpause(origin as T::RuntimeOrigin, PauseScope::All);
+
+		assert_eq!(PalletStatus::<T>::get(), PauseStatus::default());
+		assert_last_event::<T>(Event::Unpaused { scope: PauseScope::All }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+    fn set_attesters<T: Config>(
+		a: Linear<0, { T::MaxAttesters::get() }>
+	) -> Result<(), BenchmarkError> {
+		let attesters: BoundedVec<T::AccountId, T::MaxAttesters> =
+			BoundedVec::truncate_from((0..a).map(|i| account("attester", i, SEED)).collect());
+
+		frame_system::Pallet::<T>::set_block_number(One::one());
+
+        #[extrinsic_call]
+		set_attesters(RawOrigin::Root, attesters.clone());
+
+		assert_eq!(Attesters::<T>::get(), attesters);
+		assert_last_event::<T>(Event::AttestersSet { attesters }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+    fn prune_outcomes<T: Config>(
+		x: Linear<0, 1000>
+	) -> Result<(), BenchmarkError> {
+		let submitter: T::AccountId = account("submitter", 0, SEED);
+		let retention = T::OutcomeRetention::get().max(One::one());
+		let ended_at: frame_system::pallet_prelude::BlockNumberFor<T> = One::one();
+		let now = ended_at + retention;
+
+		for i in 0..x {
+			let post_id: [u8; 32] = sp_io::hashing::blake2_256(&i.to_le_bytes());
+			PostOutcomes::<T>::insert(post_id, PostOutcome {
+				submitter: submitter.clone(),
+				bull_votes: Zero::zero(),
+				bear_votes: Zero::zero(),
+				result: Direction::Tie,
+				rewarded: Zero::zero(),
+				slashed: Zero::zero(),
+				ended_at,
+			});
+			OutcomeExpiries::<T>::insert(now, post_id, ());
+		}
+
+		#[block]
+		{
+			BullPosting::<T>::prune_outcomes(now);
+		}
+
+		assert_eq!(OutcomeExpiries::<T>::iter_prefix(now).count(), 0);
+		if !T::OutcomeRetention::get().is_zero() {
+			assert_eq!(PostOutcomes::<T>::iter().count(), 0);
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+    fn prune_voter_records<T: Config>(
+		x: Linear<0, 1000>
+	) -> Result<(), BenchmarkError> {
+		let retention = T::OutcomeRetention::get().max(One::one());
+		let resolved_at: frame_system::pallet_prelude::BlockNumberFor<T> = One::one();
+		let now = resolved_at + retention;
+
+		for i in 0..x {
+			let voter: T::AccountId = account("voter", i, SEED);
+			VoterRecords::<T>::insert(&voter, VoterRecord {
+				correct: 1,
+				incorrect: 1,
+				ties: 1,
+				resolved_at,
+			});
+			VoterRecordExpiries::<T>::insert(now, voter, ());
+		}
+
+		#[block]
+		{
+			BullPosting::<T>::prune_voter_records(now);
+		}
+
+		assert_eq!(VoterRecordExpiries::<T>::iter_prefix(now).count(), 0);
+		if !T::OutcomeRetention::get().is_zero() {
+			assert_eq!(VoterRecords::<T>::iter().count(), 0);
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+    fn migrate_v0_to_v1_step<T: Config>() -> Result<(), BenchmarkError> {
+		let post_id: [u8; 32] = sp_io::hashing::blake2_256(b"migrated post");
+		StorageVersion::new(0).put::<BullPosting<T>>();
+		v0::Posts::<T>::insert(post_id, v0::OldPost {
+			submitter: account("submitter", 0, SEED),
+			bond: Zero::zero(),
+			bull_votes: One::one(),
+			bear_votes: Zero::zero(),
+			voting_until: T::VotingPeriod::get(),
+			ended: true,
+		});
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			LazyMigrationV0ToV1::<T>::step(None, &mut meter).map_err(|_| BenchmarkError::Stop("Migration step failed"))?;
+		}
+
+		assert!(migrations::v1::Posts::<T>::get(post_id).is_some_and(|post| post.result == Some(Direction::Bullish)));
+		assert_eq!(BullPosting::<T>::on_chain_storage_version(), StorageVersion::new(1));
+		Ok(())
+	}
+
+	#[benchmark]
+    fn migrate_v1_to_v2_step<T: Config>(
+		x: Linear<0, MAX_VOTERS>
+	) -> Result<(), BenchmarkError> {
+		let post_id: [u8; 32] = sp_io::hashing::blake2_256(b"migrated post");
+		let voters: Vec<T::AccountId> = (0..x).map(|i| account("voter", i, SEED)).collect();
+		StorageVersion::new(1).put::<BullPosting<T>>();
+		v1::Voters::<T>::insert(post_id, voters);
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			LazyMigrationV1ToV2::<T>::step(None, &mut meter).map_err(|_| BenchmarkError::Stop("Migration step failed"))?;
+		}
+
+		assert_eq!(Voters::<T>::iter_key_prefix(post_id).count() as u32, x);
+		assert_eq!(BullPosting::<T>::on_chain_storage_version(), StorageVersion::new(2));
+		Ok(())
+	}
+
+	#[benchmark]
+    fn migrate_v2_to_v3_step<T: Config>() -> Result<(), BenchmarkError> {
+		let post_id: [u8; 32] = sp_io::hashing::blake2_256(b"migrated post");
+		StorageVersion::new(2).put::<BullPosting<T>>();
+		v2::Posts::<T>::insert(post_id, v2::OldPost {
+			submitter: account("submitter", 0, SEED),
+			bond: Zero::zero(),
+			bull_votes: Zero::zero(),
+			bear_votes: Zero::zero(),
+			submitted_at: Zero::zero(),
+			voting_until: One::one(),
+			result: None,
+		});
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			LazyMigrationV2ToV3::<T>::step(None, &mut meter).map_err(|_| BenchmarkError::Stop("Migration step failed"))?;
+		}
+
+		assert!(Posts::<T>::get(post_id).is_some_and(|post| post.rounds.is_empty()));
+		assert_eq!(BullPosting::<T>::on_chain_storage_version(), StorageVersion::new(3));
+		Ok(())
+	}
+
+	#[benchmark]
+    fn migrate_v3_to_v4_step<T: Config>() -> Result<(), BenchmarkError> {
+		let post_id: [u8; 32] = sp_io::hashing::blake2_256(b"migrated post");
+		let submitter: T::AccountId = account("submitter", 0, SEED);
+		StorageVersion::new(3).put::<BullPosting<T>>();
+		Posts::<T>::insert(post_id, Post {
+			submitter: submitter.clone(),
+			bond: Zero::zero(),
+			bull_votes: Zero::zero(),
+			bear_votes: Zero::zero(),
+			submitted_at: Zero::zero(),
+			voting_until: One::one(),
+			result: None,
+			rounds: Default::default(),
+		});
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			LazyMigrationV3ToV4::<T>::step(None, &mut meter).map_err(|_| BenchmarkError::Stop("Migration step failed"))?;
+		}
+
+		assert_eq!(Submissions::<T>::get(submitter), Some((1, Zero::zero())));
+		assert_eq!(BullPosting::<T>::on_chain_storage_version(), StorageVersion::new(4));
+		Ok(())
+	}
+
+	#[benchmark]
+    fn migrate_v4_to_v5_step<T: Config>() -> Result<(), BenchmarkError> {
+		let post_id: [u8; 32] = sp_io::hashing::blake2_256(b"migrated post");
+		let voter: T::AccountId = account("voter", 0, SEED);
+		let amount: BalanceOf<T> = T::VoteMinimum::get().into();
+		StorageVersion::new(4).put::<BullPosting<T>>();
+		Votes::<T>::insert(&voter, post_id, (amount, Direction::Bullish));
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			LazyMigrationV4ToV5::<T>::step(None, &mut meter).map_err(|_| BenchmarkError::Stop("Migration step failed"))?;
+		}
+
+		assert_eq!(VoteAmounts::<T>::get(&voter).into_inner(), [amount]);
+		assert_eq!(BullPosting::<T>::on_chain_storage_version(), StorageVersion::new(5));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(BullPosting, crate::mock::new_test_ext(), crate::mock::Test);
 }
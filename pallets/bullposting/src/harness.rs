@@ -0,0 +1,214 @@
+//! Runs random sequences of calls against the mock runtime.
+//!
+//! Each [`Action`] is a call from one of [`ACCOUNTS`] accounts on one of [`URLS`] posts, or a jump
+//! forward in blocks. Calls are allowed to fail; after every step [`run`] checks that:
+//!
+//! - Total issuance equals the starting issuance plus everything minted as rewards minus everything
+//!   burned as slashes, according to the `PostEnded`, `PostRemoved`, `ReportSlashed`,
+//!   `AppealSettled` and `StakeSettled` events.
+//! - The balances of all accounts add up to the total issuance.
+//! - [`Pallet::do_try_state`](crate::Pallet::do_try_state) passes.
+//!
+//! Once the sequence is done, every post is ended and resolved, after which no post, vote, hold or
+//! freeze may be left behind.
+
+use crate::{mock::*, Direction, Event, ModerationDecision, Posts, Removals, Reports, Submissions, VoteCounts, VoteWeights, Voters, Votes};
+use frame_support::traits::fungible::{Inspect, InspectFreeze, InspectHold};
+use scale_info::prelude::{format, vec::Vec};
+
+/// Number of accounts making calls.
+pub const ACCOUNTS: u8 = 16;
+/// Number of distinct post URLs the calls are spread over.
+pub const URLS: u8 = 8;
+/// Free balance of every account at genesis.
+pub const STARTING_BALANCE: Balance = 10_000;
+
+/// One step of a call sequence.
+///
+/// Accounts and URLs are picked modulo [`ACCOUNTS`] and [`URLS`], amounts modulo half of
+/// [`STARTING_BALANCE`] and block jumps modulo half of the `VotingPeriod`, so that any input maps
+/// to a step that has a chance of succeeding.
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "fuzzing", derive(arbitrary::Arbitrary))]
+pub enum Action {
+    SubmitPost { who: u8, url: u8, bond: u16 },
+    SubmitVote { who: u8, url: u8, amount: u16, direction: u8 },
+    UpdateVote { who: u8, url: u8, amount: u16, direction: u8 },
+    AdvanceBlocks { blocks: u16 },
+    EndPost { who: u8, url: u8 },
+    ResolveVoting { who: u8, url: u8 },
+    ReportPost { who: u8, url: u8 },
+    ForceRemovePost { url: u8, upheld: bool },
+    AppealPost { who: u8, url: u8, bond: u16 },
+}
+
+fn account(who: u8) -> u64 {
+    u64::from(who % ACCOUNTS)
+}
+
+fn url(url: u8) -> Vec<u8> {
+    format!("https://example.com/{}", url % URLS).into_bytes()
+}
+
+fn amount(amount: u16) -> Balance {
+    Balance::from(amount) % (STARTING_BALANCE / 2)
+}
+
+fn direction(direction: u8) -> Direction {
+    match direction % 3 {
+        0 => Direction::Bullish,
+        1 => Direction::Bearish,
+        _ => Direction::Tie,
+    }
+}
+
+impl Action {
+    /// Applies the step, ignoring whether the call succeeded.
+    pub fn apply(&self) {
+        let _ = match *self {
+            Action::SubmitPost { who, url: u, bond } => Bullposting::try_submit_post(
+                RuntimeOrigin::signed(account(who)),
+                url(u),
+                amount(bond),
+            ),
+            Action::SubmitVote { who, url: u, amount: a, direction: d } => Bullposting::try_submit_vote(
+                RuntimeOrigin::signed(account(who)),
+                url(u),
+                amount(a),
+                direction(d),
+            ),
+            Action::UpdateVote { who, url: u, amount: a, direction: d } => Bullposting::try_update_vote(
+                RuntimeOrigin::signed(account(who)),
+                url(u),
+                amount(a),
+                direction(d),
+            ),
+            Action::AdvanceBlocks { blocks } => {
+                let period: u64 = <Test as crate::Config>::VotingPeriod::get();
+                System::set_block_number(System::block_number() + u64::from(blocks) % (period / 2) + 1);
+                Ok(())
+            },
+            Action::EndPost { who, url: u } => Bullposting::try_end_post(
+                RuntimeOrigin::signed(account(who)),
+                url(u),
+            ).map(|_| ()).map_err(|e| e.error),
+            Action::ResolveVoting { who, url: u } => Bullposting::try_resolve_voting(
+                RuntimeOrigin::signed(account(who)),
+                url(u),
+            ).map(|_| ()).map_err(|e| e.error),
+            Action::ReportPost { who, url: u } => Bullposting::report_post(
+                RuntimeOrigin::signed(account(who)),
+                url(u),
+            ),
+            Action::ForceRemovePost { url: u, upheld } => Bullposting::force_remove_post(
+                RuntimeOrigin::root(),
+                url(u),
+                if upheld { ModerationDecision::Upheld } else { ModerationDecision::Dismissed },
+            ),
+            Action::AppealPost { who, url: u, bond } => Bullposting::appeal_post(
+                RuntimeOrigin::signed(account(who)),
+                url(u),
+                amount(bond),
+            ),
+        };
+    }
+}
+
+/// Tokens minted and burned by the pallet so far.
+#[derive(Default)]
+struct Ledger {
+    issuance: Balance,
+    minted: Balance,
+    burned: Balance,
+}
+
+impl Ledger {
+    /// Records the rewards and slashes of the events deposited since the last check.
+    fn record_events(&mut self) {
+        for record in System::events() {
+            match record.event {
+                RuntimeEvent::Bullposting(Event::PostEnded { rewarded, slashed, .. }) => {
+                    self.minted += rewarded;
+                    self.burned += slashed;
+                },
+                RuntimeEvent::Bullposting(Event::PostRemoved { slashed, .. }) => self.burned += slashed,
+                RuntimeEvent::Bullposting(Event::ReportSlashed { deposit, .. }) => self.burned += deposit,
+                RuntimeEvent::Bullposting(Event::AppealSettled { slashed, .. }) => self.burned += slashed,
+                RuntimeEvent::Bullposting(Event::StakeSettled { winnings, lost, .. }) => {
+                    self.minted += winnings;
+                    self.burned += lost;
+                },
+                _ => {},
+            }
+        }
+        System::reset_events();
+    }
+
+    fn check(&mut self, step: &str) {
+        self.record_events();
+
+        assert_eq!(
+            Balances::total_issuance(),
+            self.issuance + self.minted - self.burned,
+            "issuance is not conserved after {step}",
+        );
+
+        let total: Balance = (0..ACCOUNTS).map(|who| Balances::total_balance(&account(who))).sum();
+        assert_eq!(total, Balances::total_issuance(), "balances do not add up to the issuance after {step}");
+
+        if let Err(e) = Bullposting::do_try_state() {
+            panic!("try_state failed after {step}: {e:?}");
+        }
+    }
+}
+
+/// Runs `actions` on a fresh mock runtime, then ends and resolves every post, checking the
+/// invariants after each step.
+pub fn run(actions: &[Action]) {
+    let balances = (0..ACCOUNTS).map(|who| (account(who), STARTING_BALANCE)).collect();
+
+    new_test_ext_with_balances(balances).execute_with(|| {
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        // Pay keepers out of the storage rent, which moves tokens between accounts without changing the issuance
+        KeeperReward::set(10);
+
+        let mut ledger = Ledger { issuance: Balances::total_issuance(), ..Default::default() };
+
+        for action in actions {
+            action.apply();
+            ledger.check(&format!("{action:?}"));
+        }
+
+        // Close out every post, after any appeal round and appeal period
+        let period: u64 = <Test as crate::Config>::VotingPeriod::get()
+            + <Test as crate::Config>::AppealPeriod::get()
+            + <Test as crate::Config>::AppealVotingPeriod::get();
+        System::set_block_number(System::block_number() + period);
+
+        for u in 0..URLS {
+            Action::EndPost { who: 0, url: u }.apply();
+            ledger.check("ending every post");
+
+            // Each call unfreezes up to `UnfreezeLimit` votes, which covers every account here
+            Action::ResolveVoting { who: 0, url: u }.apply();
+            ledger.check("resolving every post");
+        }
+
+        assert_eq!(Posts::<Test>::iter().count(), 0, "posts left after resolving every post");
+        assert_eq!(Votes::<Test>::iter().count(), 0, "votes left after resolving every post");
+        assert_eq!(Voters::<Test>::iter().count(), 0, "voters left after resolving every post");
+        assert_eq!(VoteCounts::<Test>::iter().count(), 0, "vote counts left after resolving every post");
+        assert_eq!(VoteWeights::<Test>::iter().count(), 0, "vote weights left after resolving every post");
+        assert_eq!(Reports::<Test>::iter().count(), 0, "reports left after resolving every post");
+        assert_eq!(Removals::<Test>::iter().count(), 0, "removals left after resolving every post");
+        assert_eq!(Submissions::<Test>::iter().count(), 0, "submissions left after resolving every post");
+
+        for who in 0..ACCOUNTS {
+            let who = account(who);
+            assert_eq!(Balances::total_balance_on_hold(&who), 0, "account {who} still has tokens on hold");
+            assert_eq!(Balances::balance_frozen(&crate::FreezeReason::Vote.into(), &who), 0, "account {who} still has a vote frozen");
+        }
+    });
+}
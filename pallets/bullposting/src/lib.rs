@@ -10,8 +10,13 @@ pub use pallet::*;
 
 // FRAME pallets require their own "mock runtimes" to be able to run unit tests. This module
 // contains a mock runtime specific for testing this pallet's functionality.
-#[cfg(test)]
-mod mock;
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod mock;
+
+// Drives the mock runtime with random sequences of calls, checking the pallet's invariants after
+// each one. Shared by the property tests and the `cargo fuzz` target.
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod harness;
 
 // This module contains the unit tests for this pallet.
 // Learn about pallet unit testing here: https://docs.substrate.io/test/unit-testing/
@@ -26,6 +31,33 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+// Storage migrations for this pallet. Each layout change bumps the `STORAGE_VERSION` and adds a
+// versioned migration here that the runtime includes in its `Migrations` tuple.
+pub mod migrations;
+
+/// The key type of the keys the offchain worker signs its URL attestations with.
+pub const KEY_TYPE: frame_support::sp_runtime::KeyTypeId = frame_support::sp_runtime::KeyTypeId(*b"bull");
+
+// The `sr25519` keys of `KEY_TYPE` the offchain worker signs attestations with. Nodes of the `Attesters` need one in
+// their keystore, e.g. inserted with the `author_insertKey` RPC.
+pub mod crypto {
+    use super::KEY_TYPE;
+    use frame_support::sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// Signs attestations with a key of `KEY_TYPE`, for runtimes whose signatures are `MultiSignature`.
+    pub struct AttesterAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AttesterAuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sr25519::Signature;
+        type GenericPublic = sr25519::Public;
+    }
+}
+
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet]
 pub mod pallet {
@@ -39,20 +71,55 @@ pub mod pallet {
     use scale_info::prelude::{fmt::Debug, vec::Vec};
     use frame_support::{
         traits::{
-            tokens::{fungible, Preservation, Fortitude, Precision},
+            tokens::{fungible, Preservation, Fortitude, Precision, Restriction},
             fungible::{Inspect, Mutate, MutateHold, MutateFreeze},
         },
         sp_runtime::{
-            traits::{CheckedSub, Zero},
+            offchain::{http, Duration, Timestamp},
+            offchain::storage::StorageValueRef,
+            offchain::storage_lock::{BlockAndTime, StorageLock},
+            traits::{CheckedSub, IdentifyAccount, SaturatedConversion, Saturating, Zero},
+            helpers_128bit,
+            Perbill,
             Permill,
             Percent,
+            Rounding,
         },
+        storage::with_storage_layer,
         BoundedVec,
     };
+    use frame_system::offchain::{AppCrypto, CreateInherent, SignedPayload, Signer, SigningTypes, SubmitTransaction};
+
+    /// The in-code storage version.
+    const STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+
+    /// The target of the pallet's log messages.
+    const LOG_TARGET: &str = "runtime::bullposting";
+
+    /// The offchain storage key of the lock that keeps offchain workers of concurrently imported blocks from submitting the same transactions.
+    const OFFCHAIN_LOCK: &[u8] = b"bullposting::keeper::lock";
+
+    /// The prefix of the offchain storage keys recording the block each keeper transaction was submitted at.
+    const OFFCHAIN_SUBMITTED: &[u8] = b"bullposting::keeper::submitted";
+
+    /// The prefix of the offchain storage keys the URL of each post is indexed under for the offchain worker.
+    const OFFCHAIN_URL: &[u8] = b"bullposting::url";
+
+    /// How long the offchain worker waits for the URLs it fetches, in milliseconds.
+    const FETCH_TIMEOUT_MS: u64 = 5_000;
+
+    /// The maximum number of bytes of a fetched page that are read and hashed.
+    const MAX_FETCH_LEN: usize = 1024 * 1024;
+
+    /// The key in the persistent offchain storage the SCALE encoded URL of the post with the given ID is indexed under.
+    pub fn url_key(id: &[u8; 32]) -> Vec<u8> {
+        (OFFCHAIN_URL, id).encode()
+    }
 
     // The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
     // (`Call`s) in this pallet.
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// The pallet's configuration trait.
@@ -61,7 +128,7 @@ pub mod pallet {
     /// These types are defined generically and made concrete when the pallet is declared in the
     /// `runtime/src/lib.rs` file of your chain.
     #[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config + CreateInherent<Call<Self>> + SigningTypes {
         /// The overarching runtime event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         /// A type representing the weights required by the dispatchables of this pallet.
@@ -124,10 +191,6 @@ pub mod pallet {
         #[pallet::constant]
         type VoteMinimum: Get<u32>;
 
-        /// The maximum number of accounts that can vote on a particular post.
-        #[pallet::constant]
-        type MaxVoters: Get<u32>;
-
         /// The amount of tokens locked to store a post in storage.
         #[pallet::constant]
         type StorageRent: Get<u32>;
@@ -137,16 +200,139 @@ pub mod pallet {
         type MaxUrlLength: Get<u32>;
 
         /// The maximum amount of unfreezes that can be done per `try_resolve_voting`.
+        /// Refunding or slashing a report deposit counts towards the same limit.
         #[pallet::constant]
         type UnfreezeLimit: Get<u32>;
 
+        /// The origin allowed to remove posts with `force_remove_post`.
+        type ModeratorOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The origin allowed to submit posts, eg. `EnsureSigned` or the members of a permissioned board.
+        type SubmitOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// The origin allowed to vote on posts and update their votes, eg. `EnsureSigned` or the members of a permissioned board.
+        type VoteOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+        /// The origin allowed to pause and unpause the pallet's calls with `pause` and `unpause`.
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The amount of tokens held from an account when it reports a post.
+        #[pallet::constant]
+        type ReportDeposit: Get<u32>;
+
+        /// The number of blocks after a round's voting period in which its verdict can be appealed.
+        /// Posts can only be ended once this has passed, unless they cannot be appealed any further.
+        #[pallet::constant]
+        type AppealPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The number of blocks that votes will last in an appeal round.
+        #[pallet::constant]
+        type AppealVotingPeriod: Get<BlockNumberFor<Self>>;
+
+        /// The minimum vote size accepted in appeal rounds, votes BELOW this number will be rejected.
+        #[pallet::constant]
+        type AppealVoteMinimum: Get<u32>;
+
+        /// The coefficient used to determine the minimum bond of an appeal, based on the bond of the round before it
+        /// (the submitter's bond for the first appeal).
+        /// A value of 100 requires the same bond, a value of 200 requires twice the bond.
+        #[pallet::constant]
+        type AppealBondCoefficient: Get<u32>;
+
+        /// The maximum number of times a post's verdict can be appealed.
+        #[pallet::constant]
+        type MaxAppeals: Get<u32>;
+
+        /// The maximum number of items in a batch call such as `try_submit_votes`.
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// The maximum number of posts an account can have submitted that are not yet resolved.
+        #[pallet::constant]
+        type MaxActivePosts: Get<u32>;
+
+        /// The maximum number of votes an account can have that are not yet unfrozen. An account's votes share one freeze,
+        /// kept at the largest of them, so their amounts are stored together in `VoteAmounts`.
+        #[pallet::constant]
+        type MaxVotesPerAccount: Get<u32>;
+
+        /// The number of blocks an account has to wait after submitting a post before submitting another.
+        #[pallet::constant]
+        type CooldownPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Determines whether submitters can vote on their own posts, see [`SelfVotePolicy`].
+        #[pallet::constant]
+        type SelfVotePolicy: Get<SelfVotePolicy>;
+
+        /// The amount of tokens paid to the account that ends a post, and again to the account that finishes resolving it.
+        /// Both are paid out of the submitter's storage rent, so twice this must not be more than `StorageRent`.
+        #[pallet::constant]
+        type KeeperReward: Get<u32>;
+
+        /// The priority of the unsigned `auto_end_post` and `auto_resolve_voting` transactions submitted by the offchain worker.
+        #[pallet::constant]
+        type UnsignedPriority: Get<TransactionPriority>;
+
+        /// The number of blocks the offchain worker's unsigned transactions stay valid for.
+        /// The offchain worker does not submit another transaction for the same post within this period.
+        #[pallet::constant]
+        type UnsignedLongevity: Get<BlockNumberFor<Self>>;
+
+        /// Whether the offchain worker fetches the URLs of new posts and submits their attestations.
+        /// Requires the node to run with offchain indexing enabled.
+        #[pallet::constant]
+        type AttestUrls: Get<bool>;
+
+        /// The maximum length of the page title stored in an attestation, longer titles are truncated.
+        #[pallet::constant]
+        type MaxTitleLength: Get<u32>;
+
+        /// The keys the offchain worker signs its attestations with. Only attestations signed by one of the `Attesters` are accepted.
+        type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+
+        /// The maximum number of accounts in `Attesters`.
+        #[pallet::constant]
+        type MaxAttesters: Get<u32>;
+
+        /// The number of blocks a post's outcome is kept in `PostOutcomes` after the post is ended, zero to keep outcomes forever.
+        /// Outcomes recorded while a different retention was configured are only pruned if they have also outlived this one.
+        /// Voter records are pruned once this has passed since the last of their account's votes was resolved.
+        #[pallet::constant]
+        type OutcomeRetention: Get<BlockNumberFor<Self>>;
+
+        /// Whether votes are stakes in a parimutuel market on the verdict, held rather than frozen. The verdict is fixed when the
+        /// post is ended, and as the post is resolved its losing side's stakes are shared among its winning side, less `ParimutuelFee`.
+        /// Stakes on posts removed by a moderator are refunded. Votes are settled according to the
+        /// current setting, so it must only be changed while no votes are open.
+        #[pallet::constant]
+        type Parimutuel: Get<bool>;
+
+        /// The share of the losing side's stakes burned when the votes of a post are settled in parimutuel mode.
+        #[pallet::constant]
+        type ParimutuelFee: Get<Percent>;
+
+        /// How much votes are weighted by the voter's record in `VoterRecords`, zero to count every vote at its size.
+        /// A vote counts towards the tallies as its size times a multiplier from `1 - AccuracyWeight` for voters who were never
+        /// on the side of the verdict to `1 + AccuracyWeight` for voters who always were. Voters without a record count as never
+        /// having been on the side of the verdict, so a new account cannot outweigh an account with a poor record.
+        /// Parimutuel payouts are shared by the tallies, so this must be zero in parimutuel mode.
+        #[pallet::constant]
+        type AccuracyWeight: Get<Percent>;
+
     }
 
     pub type BalanceOf<T> =
         <<T as Config>::NativeBalance as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
+    /// A post submitted at genesis as (submitter, url, bond, voting period offset)
+    pub type GenesisPost<T> = (<T as frame_system::Config>::AccountId, Vec<u8>, BalanceOf<T>, BlockNumberFor<T>);
+
+    /// A vote submitted at genesis as (voter, url, vote amount, direction)
+    pub type GenesisVote<T> = (<T as frame_system::Config>::AccountId, Vec<u8>, BalanceOf<T>, Direction);
+
     /// Used for the direction of votes and results
-    #[derive(Debug, PartialEq, Clone, Encode, Decode, TypeInfo, Default, MaxEncodedLen)]
+    #[derive(Debug, PartialEq, Clone, Encode, Decode, TypeInfo, Default, MaxEncodedLen, frame_support::Serialize, frame_support::Deserialize)]
+    #[serde(crate = "frame_support::__private::serde")]
     pub enum Direction {
         #[default]
         Bullish,
@@ -163,6 +349,15 @@ pub mod pallet {
         // Locked for storage rent, unlockable after voting ends
         #[codec(index = 1)]
         StorageRent,
+        /// Deposit of a post report, refunded or slashed once the post is resolved
+        #[codec(index = 2)]
+        ReportDeposit,
+        /// Bond of an appeal, refunded if the final verdict differs from the appealed one
+        #[codec(index = 3)]
+        AppealBond,
+        /// Stake of a vote in parimutuel mode, refunded with its winnings or burned once the post is resolved
+        #[codec(index = 4)]
+        VoteStake,
 	}
 
     /// A reason for the pallet freezing funds.
@@ -173,15 +368,236 @@ pub mod pallet {
         Vote,
 	}
 
-    #[derive(MaxEncodedLen, Debug, PartialEq, Clone, Encode, Decode, TypeInfo)]
+    /// Whether submitters can vote on their own posts
+    #[derive(Debug, PartialEq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub enum SelfVotePolicy {
+        /// Submitters can vote on their own posts like any other account.
+        Allow,
+        /// Submitters cannot vote on their own posts.
+        Forbid,
+        /// Submitters' votes count towards the verdict, but they are only rewarded if the post is Bullish without their own vote.
+        ExcludeFromReward,
+    }
+
+    /// A moderator's decision when removing a post
+    #[derive(Debug, PartialEq, Clone, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub enum ModerationDecision {
+        /// The reports were justified: the submitter's bond is slashed and reporters are refunded.
+        Upheld,
+        /// The reports were not justified: the submitter's bond is released and reporters are slashed.
+        Dismissed,
+    }
+
+    /// A transaction the offchain worker submits to move a post along
+    #[derive(Debug, PartialEq, Clone, Copy, Encode, Decode)]
+    pub enum KeeperAction {
+        /// The post's verdict can no longer be appealed, so it can be ended with `auto_end_post`.
+        End,
+        /// The post has been ended, so its votes can be unfrozen with `auto_resolve_voting`.
+        Resolve,
+        /// The post's URL has not been fetched yet, so the result can be stored with `attest_url`.
+        Attest,
+    }
+
+    /// The calls paused or unpaused by `pause` and `unpause`
+    #[derive(Debug, PartialEq, Clone, Copy, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub enum PauseScope {
+        /// Submitting posts.
+        Submissions,
+        /// Submitting and updating votes, and appealing verdicts.
+        Voting,
+        /// Ending and resolving posts, including the offchain worker's `auto_end_post` and `auto_resolve_voting`.
+        Ending,
+        /// All of the above.
+        All,
+    }
+
+    /// Which of the pallet's calls are paused
+    #[derive(Debug, PartialEq, Clone, Copy, Default, Encode, Decode, TypeInfo, MaxEncodedLen)]
+    pub struct PauseStatus {
+        /// Whether the [`PauseScope::Submissions`] calls are paused.
+        pub submissions: bool,
+        /// Whether the [`PauseScope::Voting`] calls are paused.
+        pub voting: bool,
+        /// Whether the [`PauseScope::Ending`] calls are paused.
+        pub ending: bool,
+    }
+
+    impl PauseStatus {
+        /// Whether the calls of `scope` are paused, for [`PauseScope::All`] whether every scope is.
+        pub fn is_paused(&self, scope: PauseScope) -> bool {
+            match scope {
+                PauseScope::Submissions => self.submissions,
+                PauseScope::Voting => self.voting,
+                PauseScope::Ending => self.ending,
+                PauseScope::All => self.submissions && self.voting && self.ending,
+            }
+        }
+
+        fn set(&mut self, scope: PauseScope, paused: bool) {
+            match scope {
+                PauseScope::Submissions => self.submissions = paused,
+                PauseScope::Voting => self.voting = paused,
+                PauseScope::Ending => self.ending = paused,
+                PauseScope::All => *self = PauseStatus { submissions: paused, voting: paused, ending: paused },
+            }
+        }
+    }
+
+    /// The result of the offchain worker fetching the URL of a post
+    #[derive(MaxEncodedLen, DebugNoBound, PartialEqNoBound, CloneNoBound, Encode, Decode, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Attestation<T: Config> {
+        /// The HTTP status code of the response, 0 if the URL could not be fetched.
+        pub status: u16,
+        /// The blake2_256 hash of the first `MAX_FETCH_LEN` bytes of the response body, zeroed if the URL could not be fetched.
+        pub content_hash: [u8; 32],
+        /// The contents of the page's `<title>` tag, empty if it has none.
+        pub title: BoundedVec<u8, T::MaxTitleLength>,
+        /// The block the attestation was stored at.
+        pub attested_at: BlockNumberFor<T>,
+    }
+
+    /// An attestation of a post's URL as submitted with `attest_url`, signed by the attester's `AuthorityId` key
+    #[derive(DebugNoBound, PartialEqNoBound, EqNoBound, CloneNoBound, Encode, Decode, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct AttestationPayload<T: Config> {
+        /// The post ID.
+        pub id: [u8; 32],
+        /// The HTTP status code of the response, 0 if the URL could not be fetched.
+        pub status: u16,
+        /// The blake2_256 hash of the first `MAX_FETCH_LEN` bytes of the response body, zeroed if the URL could not be fetched.
+        pub content_hash: [u8; 32],
+        /// The contents of the page's `<title>` tag, empty if it has none.
+        pub title: BoundedVec<u8, T::MaxTitleLength>,
+        /// The key the payload is signed with, which must belong to one of the `Attesters`.
+        pub public: T::Public,
+    }
+
+    impl<T: Config> SignedPayload<T> for AttestationPayload<T> {
+        fn public(&self) -> T::Public {
+            self.public.clone()
+        }
+    }
+
+    /// A voting round whose verdict was appealed
+    #[derive(MaxEncodedLen, DebugNoBound, PartialEqNoBound, CloneNoBound, Encode, Decode, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct Round<T: Config> {
+        /// The account that appealed the round's verdict.
+        pub appellant: T::AccountId,
+        /// The bond held from the appellant.
+        pub bond: BalanceOf<T>,
+        pub bull_votes: BalanceOf<T>,
+        pub bear_votes: BalanceOf<T>,
+        /// The block the round's voting ended at.
+        pub voting_until: BlockNumberFor<T>,
+        /// The verdict that was appealed.
+        pub result: Direction,
+    }
+
+    // The std derives would require `T` and `T::MaxAppeals` to implement these traits as well
+    #[derive(MaxEncodedLen, DebugNoBound, PartialEqNoBound, CloneNoBound, Encode, Decode, TypeInfo)]
     #[scale_info(skip_type_params(T))]
     pub struct Post<T: Config> {
         pub submitter: T::AccountId,
         pub bond: BalanceOf<T>,
         pub bull_votes: BalanceOf<T>,
         pub bear_votes: BalanceOf<T>,
+        /// The block the post was submitted at.
+        pub submitted_at: BlockNumberFor<T>,
         pub voting_until: BlockNumberFor<T>,
-        pub ended: bool,
+        /// The verdict of the post, `None` until the post is ended.
+        pub result: Option<Direction>,
+        /// The earlier voting rounds of the post that were appealed, oldest first.
+        /// The tallies and `voting_until` above belong to the current round.
+        pub rounds: BoundedVec<Round<T>, T::MaxAppeals>,
+    }
+
+    /// The votes of an account on resolved posts, by whether they sided with the verdict
+    #[derive(Debug, PartialEq, Clone, Copy, Encode, Decode, TypeInfo, Default, MaxEncodedLen)]
+    pub struct VoterRecord<BlockNumber> {
+        /// Votes in the direction of the verdict.
+        pub correct: u32,
+        /// Votes against the verdict, including Tie votes on posts that were not a tie.
+        pub incorrect: u32,
+        /// Votes on posts that ended in a tie.
+        pub ties: u32,
+        /// The block the account's last vote was resolved at.
+        pub resolved_at: BlockNumber,
+    }
+
+    impl<BlockNumber> VoterRecord<BlockNumber> {
+        /// The share of the votes on posts that did not end in a tie that sided with the verdict, none for no such votes.
+        pub fn accuracy(&self) -> Perbill {
+            let decided = self.correct.saturating_add(self.incorrect);
+            if decided == 0 {
+                Perbill::zero()
+            } else {
+                Perbill::from_rational(self.correct, decided)
+            }
+        }
+    }
+
+    /// The outcome of an ended post, kept after the post is resolved
+    #[derive(MaxEncodedLen, DebugNoBound, PartialEqNoBound, CloneNoBound, Encode, Decode, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct PostOutcome<T: Config> {
+        pub submitter: T::AccountId,
+        /// The tallies of the final voting round.
+        pub bull_votes: BalanceOf<T>,
+        pub bear_votes: BalanceOf<T>,
+        /// The verdict of the post, `Bearish` or `Tie` if a moderator removed it before it was ended.
+        pub result: Direction,
+        /// The amount of tokens minted to the submitter.
+        pub rewarded: BalanceOf<T>,
+        /// The amount of tokens slashed from the submitter's bond.
+        pub slashed: BalanceOf<T>,
+        /// The block the post was ended or removed at.
+        pub ended_at: BlockNumberFor<T>,
+    }
+
+    impl<T: Config> Post<T> {
+        /// Whether `end_post` has been called on this post.
+        pub fn is_ended(&self) -> bool {
+            self.result.is_some()
+        }
+
+        /// The direction the tallies currently point in.
+        pub fn tally(&self) -> Direction {
+            if self.bull_votes > self.bear_votes {
+                Direction::Bullish
+            } else if self.bull_votes < self.bear_votes {
+                Direction::Bearish
+            } else {
+                Direction::Tie
+            }
+        }
+
+        /// Whether the current round is an appeal round.
+        pub fn is_appealed(&self) -> bool {
+            !self.rounds.is_empty()
+        }
+
+        /// The block until which the current round's verdict can be appealed, after which the post can be ended.
+        pub fn appealable_until(&self) -> BlockNumberFor<T> {
+            if (self.rounds.len() as u32) < T::MaxAppeals::get() {
+                self.voting_until + T::AppealPeriod::get()
+            } else {
+                self.voting_until
+            }
+        }
+
+        /// The transaction that moves this post along at block `now`, if any.
+        pub fn keeper_action(&self, now: BlockNumberFor<T>) -> Option<KeeperAction> {
+            if self.is_ended() {
+                Some(KeeperAction::Resolve)
+            } else if now >= self.appealable_until() {
+                Some(KeeperAction::End)
+            } else {
+                None
+            }
+        }
     }
 
     /// Stores the post ID as the key and a post struct (with the additional info such as the submitter) as the value
@@ -202,16 +618,226 @@ pub mod pallet {
     ValueQuery,
     >;
 
-    /// Stores the list of voters on each post ID
+    /// Stores the amounts of each account's votes that are not yet unfrozen, so their shared freeze can be kept at the largest
+    /// without reading all of the account's `Votes`
     #[pallet::storage]
-    pub type Voters<T: Config> =
-        StorageMap<_, Blake2_128Concat, [u8; 32], BoundedVec<T::AccountId, T::MaxVoters>>;
+    pub type VoteAmounts<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<BalanceOf<T>, T::MaxVotesPerAccount>, ValueQuery>;
+
+    /// Stores the voters on each post ID, keyed by post first so a post's voters can be drained by prefix
+    #[pallet::storage]
+    pub type Voters<T: Config> = StorageDoubleMap<
+    _,
+    Blake2_128Concat,
+    [u8; 32],
+    Blake2_128Concat,
+    T::AccountId,
+    (),
+    >;
 
     /// Stores the number of votes on each post ID
     #[pallet::storage]
     pub type VoteCounts<T: Config> =
         StorageMap<_, Blake2_128Concat, [u8; 32], u32>;
 
+    /// Stores the deposit of each account that reported a post, keyed by post first so a post's reports can be drained by prefix
+    #[pallet::storage]
+    pub type Reports<T: Config> = StorageDoubleMap<
+    _,
+    Blake2_128Concat,
+    [u8; 32],
+    Blake2_128Concat,
+    T::AccountId,
+    BalanceOf<T>,
+    >;
+
+    /// Stores the moderator's decision on each removed post until the post is resolved
+    #[pallet::storage]
+    pub type Removals<T: Config> =
+        StorageMap<_, Blake2_128Concat, [u8; 32], ModerationDecision>;
+
+    /// Stores the number of unresolved posts of each submitter and the block of their last submission.
+    /// Removed once all their posts are resolved and the `CooldownPeriod` has passed
+    #[pallet::storage]
+    pub type Submissions<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (u32, BlockNumberFor<T>)>;
+
+    /// Stores the offchain worker's attestation of each post's URL until the post is resolved
+    #[pallet::storage]
+    pub type Attestations<T: Config> =
+        StorageMap<_, Blake2_128Concat, [u8; 32], Attestation<T>>;
+
+    /// Stores the accounts whose signed attestations `attest_url` accepts, set by root with `set_attesters`
+    #[pallet::storage]
+    pub type Attesters<T: Config> = StorageValue<_, BoundedVec<T::AccountId, T::MaxAttesters>, ValueQuery>;
+
+    /// Stores the URLs of the genesis posts until they are indexed for the offchain worker at block 1,
+    /// as offchain indexing is not available while the genesis state is built
+    #[pallet::storage]
+    pub type GenesisUrls<T: Config> =
+        StorageMap<_, Blake2_128Concat, [u8; 32], BoundedVec<u8, T::MaxUrlLength>>;
+
+    /// Stores which of the pallet's calls are paused by the `PauseOrigin`
+    #[pallet::storage]
+    pub type PalletStatus<T: Config> = StorageValue<_, PauseStatus, ValueQuery>;
+
+    /// Stores the outcome of each ended post, kept after the post is resolved until `OutcomeRetention` has passed
+    #[pallet::storage]
+    pub type PostOutcomes<T: Config> =
+        StorageMap<_, Blake2_128Concat, [u8; 32], PostOutcome<T>>;
+
+    /// Stores the record of each account that voted on a resolved post, until `OutcomeRetention` has passed since its last one
+    #[pallet::storage]
+    pub type VoterRecords<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, VoterRecord<BlockNumberFor<T>>, ValueQuery>;
+
+    /// Stores the weight each vote counts towards its post's tallies with, if `AccuracyWeight` made it differ from the vote's size
+    #[pallet::storage]
+    pub type VoteWeights<T: Config> = StorageDoubleMap<
+    _,
+    Blake2_128Concat,
+    [u8; 32],
+    Blake2_128Concat,
+    T::AccountId,
+    BalanceOf<T>,
+    >;
+
+    /// Stores the IDs of the outcomes to prune at each block, keyed by block first so a block's outcomes can be drained by prefix
+    #[pallet::storage]
+    pub type OutcomeExpiries<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    BlockNumberFor<T>,
+    Blake2_128Concat,
+    [u8; 32],
+    (),
+    >;
+
+    /// Stores the accounts whose records to prune at each block, keyed by block first so a block's records can be drained by prefix
+    #[pallet::storage]
+    pub type VoterRecordExpiries<T: Config> = StorageDoubleMap<
+    _,
+    Twox64Concat,
+    BlockNumberFor<T>,
+    Blake2_128Concat,
+    T::AccountId,
+    (),
+    >;
+
+    /// Posts and votes to start the chain with, e.g. sample content for test networks
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Posts to submit. Voting on each post ends the given offset of blocks after genesis.
+        pub posts: Vec<GenesisPost<T>>,
+        /// Votes on the posts above.
+        pub votes: Vec<GenesisVote<T>>,
+        /// Accounts whose offchain workers attest post URLs, see `Attesters`.
+        pub attesters: Vec<T::AccountId>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            let genesis = frame_system::Pallet::<T>::block_number();
+
+            let attesters = BoundedVec::try_from(self.attesters.clone()).expect("Genesis attesters must not be more than MaxAttesters");
+            Attesters::<T>::put(attesters);
+
+            // Posts are inserted directly rather than through `submit_post`, as the `CooldownPeriod` would only allow one post per submitter
+            for (submitter, post_url, bond, voting_period) in &self.posts {
+                let post_url = Pallet::<T>::bound_url(post_url.clone()).expect("Genesis post URLs must not be empty or longer than MaxUrlLength");
+                let id = sp_io::hashing::blake2_256(&post_url);
+
+                assert!(!Posts::<T>::contains_key(id), "Genesis posts must have distinct URLs");
+                assert!(*bond >= T::BondMinimum::get().into(), "Genesis post bonds must be at least BondMinimum");
+                assert!(!voting_period.is_zero(), "Genesis posts must be open for voting");
+
+                let active_posts = Submissions::<T>::get(submitter).map_or(0, |(active_posts, _)| active_posts);
+                assert!(active_posts < T::MaxActivePosts::get(), "Genesis submitters must have at most MaxActivePosts posts");
+
+                <<T as Config>::NativeBalance>::hold(&HoldReason::PostBond.into(), submitter, *bond)
+                    .expect("Genesis submitters must be able to pay their bonds");
+                <<T as Config>::NativeBalance>::hold(&HoldReason::StorageRent.into(), submitter, T::StorageRent::get().into())
+                    .expect("Genesis submitters must be able to pay the storage rent");
+
+                Submissions::<T>::insert(submitter, (active_posts + 1, genesis));
+
+                // Indexed through `index_url` at block 1
+                GenesisUrls::<T>::insert(id, post_url);
+
+                Posts::<T>::insert(id, Post {
+                    submitter: submitter.clone(),
+                    bond: *bond,
+                    bull_votes: Zero::zero(),
+                    bear_votes: Zero::zero(),
+                    submitted_at: genesis,
+                    voting_until: genesis + *voting_period,
+                    result: None,
+                    rounds: BoundedVec::new(),
+                });
+            }
+
+            // Votes go through the same checks, freezes and tallies as `try_submit_vote`
+            for (voter, post_url, vote_amount, direction) in &self.votes {
+                assert!(*vote_amount >= T::VoteMinimum::get().into(), "Genesis votes must be at least VoteMinimum");
+                let post_url = Pallet::<T>::bound_url(post_url.clone()).expect("Genesis vote URLs must not be empty or longer than MaxUrlLength");
+
+                Pallet::<T>::submit_vote(voter.clone(), post_url, *vote_amount, direction.clone())
+                    .expect("Genesis votes must be valid votes on genesis posts");
+            }
+        }
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// Prunes the outcomes and voter records whose `OutcomeRetention` ends at this block, and indexes the genesis posts' URLs
+        /// at block 1.
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let mut weight = Self::prune_outcomes(now);
+            weight.saturating_accrue(Self::prune_voter_records(now));
+            if now.is_one() {
+                weight.saturating_accrue(Self::index_genesis_urls());
+            }
+            weight
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::KeeperReward::get().saturating_mul(2) <= T::StorageRent::get(),
+                "The keeper rewards of a post must be covered by its StorageRent"
+            );
+            assert!(
+                !T::Parimutuel::get() || T::AccuracyWeight::get().is_zero(),
+                "Votes cannot be weighted by accuracy in parimutuel mode"
+            );
+        }
+
+        /// Submits unsigned `auto_end_post` and `auto_resolve_voting` transactions for the posts that need them,
+        /// up to `MaxBatchSize` per block, so posts do not wait for a keeper to end and resolve them.
+        fn offchain_worker(now: BlockNumberFor<T>) {
+            // Offchain workers of blocks imported at the same time would otherwise submit the same transactions
+            let mut lock = StorageLock::<BlockAndTime<frame_system::Pallet<T>>>::with_block_and_time_deadline(
+                OFFCHAIN_LOCK,
+                1,
+                frame_support::sp_runtime::offchain::Duration::from_millis(6_000),
+            );
+            let Ok(_guard) = lock.try_lock() else {
+                return;
+            };
+
+            let submitted = Self::submit_keeper_transactions(now);
+            if T::AttestUrls::get() {
+                Self::attest_urls(now, T::MaxBatchSize::get().saturating_sub(submitted));
+            }
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+    }
+
     /// Events that functions in this pallet can emit.
     ///
     /// Events are a simple means of indicating to the outside world (such as dApps, chain explorers
@@ -274,54 +900,204 @@ pub mod pallet {
             account: T::AccountId,
             amount: BalanceOf<T>,
         },
+        /// Stake of a vote settled in parimutuel mode, in place of `VoteUnfrozen`.
+        StakeSettled {
+            /// The post ID.
+            id: [u8; 32],
+            voter: T::AccountId,
+            /// The amount of tokens the voter staked.
+            stake: BalanceOf<T>,
+            /// The share of the losing side's stakes minted to the voter, on top of their stake.
+            winnings: BalanceOf<T>,
+            /// The amount of the stake burned because the voter was on the losing side.
+            lost: BalanceOf<T>,
+        },
         PartiallyResolved {
             id: [u8; 32]
         },
         PostResolved {
             id: [u8; 32]
-        }
-    }
-
-    /// Errors that can be returned by this pallet.
-    ///
-    /// Errors tell users that something went wrong so it's important that their naming is
-    /// informative. Similar to events, error documentation is added to a node's metadata so it's
-    /// equally important that they have helpful documentation associated with them.
-    ///
-    /// This type of runtime error can be up to 4 bytes in size should you want to return additional
-    /// information.
-    #[pallet::error]
-    pub enum Error<T> {
-        /// Submitted URL input was too long (acceptable length configured in runtime).
-        InputTooLong,
-        /// Submitted URL was empty.
-        Empty,
-        /// Attempted bond was below the BondMinimum configured in the runtime.
-        BondTooLow,
-        /// Attempted vote was below the VoteMinimum configured in the runtime.
-        VoteTooLow,
-        /// Post already submitted.
-        PostAlreadyExists,
-        /// Insufficient available balance.
-        InsufficientFreeBalance,
-        /// Post has not been submitted.
-        PostDoesNotExist,
-        /// The post being voted upon has already reached `MaxVoters`.
-        VotersMaxed,
-        /// Account already voted on a particular post
-        AlreadyVoted,
-        /// If you try to unfreeze a vote that was already unfrozen or never happened in the first place.
-        VoteDoesNotExist,
-        /// Vote still in progress.
-        VotingStillOngoing,
-        /// Voting has ended but nobody has called try_end_voting() yet.
-        PostUnended,
-        /// Vote already closed and nded.
-        PostAlreadyEnded,
-        /// The voting period for a post has ended.
-        VotingEnded,
-    }
-
+        },
+        /// Post reported for moderation.
+        PostReported {
+            /// The post ID.
+            id: [u8; 32],
+            /// The account that reported the post.
+            reporter: T::AccountId,
+            /// The amount of tokens held as the report deposit.
+            deposit: BalanceOf<T>,
+        },
+        /// Post removed by a moderator. Its votes and reports are settled by `try_resolve_voting`.
+        PostRemoved {
+            /// The post ID.
+            id: [u8; 32],
+            /// The account that submitted the post and bonded tokens.
+            submitter: T::AccountId,
+            /// Whether the reports on the post were upheld or dismissed.
+            decision: ModerationDecision,
+            /// The amount of the submitter's bond that was slashed.
+            slashed: BalanceOf<T>,
+        },
+        /// Report deposit returned to the reporter.
+        ReportRefunded {
+            id: [u8; 32],
+            reporter: T::AccountId,
+            deposit: BalanceOf<T>,
+        },
+        /// Report deposit slashed from the reporter.
+        ReportSlashed {
+            id: [u8; 32],
+            reporter: T::AccountId,
+            deposit: BalanceOf<T>,
+        },
+        /// Verdict appealed, opening a new voting round.
+        PostAppealed {
+            /// The post ID.
+            id: [u8; 32],
+            /// The account that appealed and bonded tokens.
+            appellant: T::AccountId,
+            /// Amount of bonded tokens.
+            bond: BalanceOf<T>,
+            /// The number of the appeal, starting from 1.
+            round: u32,
+            /// End of the appeal round's voting period.
+            voting_until: BlockNumberFor<T>,
+        },
+        /// Appeal bond refunded or slashed once the post is ended or removed.
+        AppealSettled {
+            id: [u8; 32],
+            appellant: T::AccountId,
+            refunded: BalanceOf<T>,
+            slashed: BalanceOf<T>,
+        },
+        /// URL of a post fetched by the offchain worker.
+        UrlAttested {
+            /// The post ID.
+            id: [u8; 32],
+            /// The HTTP status code, 0 if the URL could not be fetched.
+            status: u16,
+            /// The hash of the response body.
+            content_hash: [u8; 32],
+        },
+        /// Keeper rewarded out of the submitter's storage rent for ending or resolving a post.
+        KeeperRewarded {
+            /// The post ID.
+            id: [u8; 32],
+            /// The account that ended or resolved the post.
+            keeper: T::AccountId,
+            /// The amount of tokens transferred to the keeper.
+            amount: BalanceOf<T>,
+        },
+        /// An item of a `try_end_posts` or `try_resolve_votings` batch failed, the other items were still applied.
+        BatchItemFailed {
+            /// The position of the item in the batch.
+            index: u32,
+            /// The error the item failed with.
+            error: DispatchError,
+        },
+        /// Post ended by root with `force_end_post`, without the timing checks of `try_end_post`.
+        PostForceEnded {
+            /// The post ID.
+            id: [u8; 32],
+            /// The verdict the post was ended with.
+            result: Direction,
+            /// Whether the verdict was given by root rather than the tallies.
+            overridden: bool,
+            /// The number of settlement steps that failed and were skipped.
+            failed: u32,
+        },
+        /// Votes and reports of a post settled by root with `force_resolve_post`.
+        PostForceResolved {
+            /// The post ID.
+            id: [u8; 32],
+            /// The number of votes and reports settled, including the failed ones.
+            settled: u32,
+            /// The number of settlement steps that failed and were skipped.
+            failed: u32,
+        },
+        /// Calls paused by the `PauseOrigin`.
+        Paused {
+            /// The calls that were paused.
+            scope: PauseScope,
+        },
+        /// Calls unpaused by the `PauseOrigin`.
+        Unpaused {
+            /// The calls that were unpaused.
+            scope: PauseScope,
+        },
+        /// The accounts allowed to attest post URLs set by root.
+        AttestersSet {
+            /// The new attesters.
+            attesters: BoundedVec<T::AccountId, T::MaxAttesters>,
+        },
+    }
+
+    /// Errors that can be returned by this pallet.
+    ///
+    /// Errors tell users that something went wrong so it's important that their naming is
+    /// informative. Similar to events, error documentation is added to a node's metadata so it's
+    /// equally important that they have helpful documentation associated with them.
+    ///
+    /// This type of runtime error can be up to 4 bytes in size should you want to return additional
+    /// information.
+    #[pallet::error]
+    pub enum Error<T> {
+        /// Submitted URL input was too long (acceptable length configured in runtime).
+        InputTooLong,
+        /// Submitted URL was empty.
+        Empty,
+        /// Attempted bond was below the BondMinimum configured in the runtime.
+        BondTooLow,
+        /// Attempted vote was below the VoteMinimum configured in the runtime.
+        VoteTooLow,
+        /// Post already submitted.
+        PostAlreadyExists,
+        /// Insufficient available balance.
+        InsufficientFreeBalance,
+        /// Post has not been submitted.
+        PostDoesNotExist,
+        /// Account already voted on a particular post
+        AlreadyVoted,
+        /// If you try to unfreeze a vote that was already unfrozen or never happened in the first place.
+        VoteDoesNotExist,
+        /// Vote still in progress.
+        VotingStillOngoing,
+        /// Voting has ended but nobody has called try_end_voting() yet.
+        PostUnended,
+        /// Vote already closed and nded.
+        PostAlreadyEnded,
+        /// The voting period for a post has ended.
+        VotingEnded,
+        /// Account already reported a particular post.
+        AlreadyReported,
+        /// Post has already been removed by a moderator.
+        PostAlreadyRemoved,
+        /// The post's verdict can still be appealed, so it cannot be ended yet.
+        AppealWindowOpen,
+        /// The appeal period for the post's verdict has passed.
+        AppealWindowClosed,
+        /// Attempted appeal bond was below the bond required for the appeal.
+        AppealBondTooLow,
+        /// The post has been appealed the maximum number of times.
+        MaxAppealsReached,
+        /// The batch has more items than the MaxBatchSize configured in the runtime.
+        BatchTooLarge,
+        /// The batch has no items.
+        EmptyBatch,
+        /// The submitter already has the MaxActivePosts configured in the runtime.
+        TooManyActivePosts,
+        /// The submitter submitted a post less than CooldownPeriod blocks ago.
+        SubmissionCooldown,
+        /// The account already has the MaxVotesPerAccount configured in the runtime that are not yet unfrozen.
+        TooManyVotes,
+        /// The SelfVotePolicy configured in the runtime forbids submitters voting on their own posts.
+        SelfVote,
+        /// The post's URL has already been attested.
+        AlreadyAttested,
+        /// The call is paused by the PauseOrigin.
+        Paused,
+    }
+
     /// The pallet's dispatchable functions ([`Call`]s).
     ///
     /// Dispatchable functions allows users to interact with the pallet and invoke state changes.
@@ -350,14 +1126,20 @@ pub mod pallet {
         /// - If the bondy is below the `BondMinimum` ([`Error::BondTooLow`])
         /// - If post input is higher than the `MaxUrlLength` set in the runtime ([`Error::InputTooLong`])
         /// - If the post has been submitted previously ([`Error::PostAlreadyExists`])
+        /// - If the submitter has `MaxActivePosts` posts that are not yet resolved ([`Error::TooManyActivePosts`])
+        /// - If the submitter submitted a post less than `CooldownPeriod` blocks ago ([`Error::SubmissionCooldown`])
         /// - If the submitter does not have sufficient free tokens for their bond and the storage rent ([`Error::InsufficientFreeBalance`])
+        /// - If the origin is not the `SubmitOrigin` ([`DispatchError::BadOrigin`])
+        /// - If submissions are paused ([`Error::Paused`])
         #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::try_submit_post(post_url.len() as u32))]
         pub fn try_submit_post(
             origin: OriginFor<T>,
             post_url: Vec<u8>,
             bond: BalanceOf<T>,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            let who = T::SubmitOrigin::ensure_origin(origin)?;
+            Self::ensure_unpaused(PauseScope::Submissions)?;
             // Ensure the post input is not empty
             ensure!(!post_url.is_empty(), Error::<T>::Empty);
 
@@ -382,17 +1164,22 @@ pub mod pallet {
         /// - If post input is higher than the `MaxUrlLength` set in the runtime ([`Error::InputTooLong`])
         /// - If the post does not exist ([`Error::PostDoesNotExist`])
         /// - If the voting has already ended ([`Error::VotingEnded`])
-        /// - If the post has already reached `MaxVoters` ([`Error::VotersMaxed`])
         /// - If they have already voted once ([`Error::AlreadyVoted`])
+        /// - If they have `MaxVotesPerAccount` votes that are not yet unfrozen ([`Error::TooManyVotes`])
+        /// - If they submitted the post and the `SelfVotePolicy` forbids it ([`Error::SelfVote`])
         /// - If the user tries to vote with more than their balance ([`Error::InsufficientFreeBalance`])
+        /// - If the origin is not the `VoteOrigin` ([`DispatchError::BadOrigin`])
+        /// - If voting is paused ([`Error::Paused`])
         #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::try_submit_vote(post_url.len() as u32))]
         pub fn try_submit_vote(
             origin: OriginFor<T>,
             post_url: Vec<u8>,
             vote_amount: BalanceOf<T>,
             direction: Direction,
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            let who = T::VoteOrigin::ensure_origin(origin)?;
+            Self::ensure_unpaused(PauseScope::Voting)?;
             // Ensure the post input is not empty
             ensure!(!post_url.is_empty(), Error::<T>::Empty);
 
@@ -419,15 +1206,20 @@ pub mod pallet {
         /// - If the post does not exist ([`Error::PostDoesNotExist`])
         /// - If the voting has already ended ([`Error::VotingEnded`])
         /// - If this particular vote doesn't exist (['Error::VoteDoesNotExist'])
+        /// - If they submitted the post and the `SelfVotePolicy` forbids it ([`Error::SelfVote`])
         /// - If the user does not have enough balance for their new vote ([`Error::InsufficientBalance`])
+        /// - If the origin is not the `VoteOrigin` ([`DispatchError::BadOrigin`])
+        /// - If voting is paused ([`Error::Paused`])
         #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::try_update_vote(post_url.len() as u32, T::MaxVotesPerAccount::get()))]
         pub fn try_update_vote(
             origin: OriginFor<T>,
             post_url: Vec<u8>,
             new_vote: BalanceOf<T>,
             direction: Direction
         ) -> DispatchResult {
-            let who = ensure_signed(origin)?;
+            let who = T::VoteOrigin::ensure_origin(origin)?;
+            Self::ensure_unpaused(PauseScope::Voting)?;
             // Ensure the post input is not empty
             ensure!(!post_url.is_empty(), Error::<T>::Empty);
 
@@ -443,7 +1235,7 @@ pub mod pallet {
 
 
         /// Ends a post, rewarding or slashing the submitter and enabling unfreeze_vote.
-        /// Callable by anyone.
+        /// Callable by anyone. The caller is paid `KeeperReward` out of the submitter's storage rent and pays no fee.
         ///
         /// ## Errors
         ///
@@ -454,25 +1246,29 @@ pub mod pallet {
         /// - If the post does not exist ([`Error::PostDoesNotExist`])
         /// - If the vote is still in progress ([`Error::VotingStillOngoing`])
         /// - If the vote has already been ended ([`Error::PostAlreadyEnded`])
+        /// - If ending is paused ([`Error::Paused`])
         #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::try_end_post(post_url.len() as u32))]
+        #[allow(clippy::useless_conversion)]
         pub fn try_end_post(
             origin: OriginFor<T>,
             post_url: Vec<u8>,
-        ) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_unpaused(PauseScope::Ending)?;
             // Ensure the post input is not empty
             ensure!(!post_url.is_empty(), Error::<T>::Empty);
 
             // Convert the post input into a bounded vec to use in the actual logic, errors if too long
             let bounded: BoundedVec<u8, T::MaxUrlLength> = BoundedVec::try_from(post_url).map_err(|_| Error::<T>::InputTooLong)?;
 
-            Self::end_post(bounded)?;
+            Self::end_post(Some(who), sp_io::hashing::blake2_256(&bounded))?;
 
-            Ok(())
+            Ok(Pays::No.into())
         }
 
         /// Unlocks the submitter's storage rent and unfreezes all votes on that post.
-        /// Callable by anyone.
+        /// Callable by anyone. The call that finishes resolving the post is paid `KeeperReward` out of the storage rent and pays no fee.
         ///
         /// ## Errors
         ///
@@ -482,458 +1278,1993 @@ pub mod pallet {
         /// - If post input is higher than the `MaxUrlLength` set in the runtime ([`Error::InputTooLong`])
         /// - If the post does not exist ([`Error::PostDoesNotExist`])
         /// - If the post is unended ([`Error::PostUnended`])
+        /// - If ending is paused ([`Error::Paused`])
         #[pallet::call_index(4)]
-        #[pallet::weight(T::WeightInfo::try_resolve_voting(T::UnfreezeLimit::get()))]
+        #[pallet::weight(T::WeightInfo::try_resolve_voting(T::UnfreezeLimit::get(), T::MaxVotesPerAccount::get()))]
+        #[allow(clippy::useless_conversion)]
         pub fn try_resolve_voting(
             origin: OriginFor<T>,
             post_url: Vec<u8>,
         ) -> DispatchResultWithPostInfo {
-            let _who = ensure_signed(origin)?;
+            let who = ensure_signed(origin)?;
+            Self::ensure_unpaused(PauseScope::Ending)?;
             // Ensure the post input is not empty
             ensure!(!post_url.is_empty(), Error::<T>::Empty);
 
             // Convert the post input into a bounded vec to use in the actual logic, errors if too long
             let bounded: BoundedVec<u8, T::MaxUrlLength> = BoundedVec::try_from(post_url).map_err(|_| Error::<T>::InputTooLong)?;
 
-            let weight_used = Self::resolve_post(bounded)?;
-
-            Ok(weight_used)
+            Self::resolve_post(Some(who), sp_io::hashing::blake2_256(&bounded))
         }
-    }
-
 
-    impl<T: Config> Pallet<T> {
-        pub(crate) fn submit_post(
-            who: T::AccountId,
-            post_url: BoundedVec<u8, T::MaxUrlLength>,
-            bond: BalanceOf<T>
+        /// Reports a post for moderation, holding `ReportDeposit` from the reporter.
+        /// The deposit is slashed if a moderator dismisses the reports, and refunded when the post is resolved otherwise.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If they submit nothing for the post_url ([`Error::Empty`])
+        /// - If post input is higher than the `MaxUrlLength` set in the runtime ([`Error::InputTooLong`])
+        /// - If the post does not exist ([`Error::PostDoesNotExist`])
+        /// - If the post has already been removed ([`Error::PostAlreadyRemoved`])
+        /// - If they have already reported the post ([`Error::AlreadyReported`])
+        /// - If the reporter does not have sufficient free tokens for the deposit ([`Error::InsufficientFreeBalance`])
+        #[pallet::call_index(5)]
+        pub fn report_post(
+            origin: OriginFor<T>,
+            post_url: Vec<u8>,
         ) -> DispatchResult {
-            let id = sp_io::hashing::blake2_256(&post_url);
-
-            // Checks if the post exists
-            ensure!(!Posts::<T>::contains_key(&id), Error::<T>::PostAlreadyExists);
-
-            let storage_rent = T::StorageRent::get();
+            let who = ensure_signed(origin)?;
+            // Ensure the post input is not empty
+            ensure!(!post_url.is_empty(), Error::<T>::Empty);
 
-            // Checks if they have enough balance available to be bonded
-            let reduc_bal = <<T as Config>::NativeBalance>::
-            reducible_balance(&who, Preservation::Preserve, Fortitude::Polite);
-            reduc_bal.checked_sub(&bond).ok_or(Error::<T>::InsufficientFreeBalance)?;
-            reduc_bal.checked_sub(&storage_rent.into()).ok_or(Error::<T>::InsufficientFreeBalance)?;
+            // Convert the post input into a bounded vec to use in the actual logic, errors if too long
+            let bounded: BoundedVec<u8, T::MaxUrlLength> = BoundedVec::try_from(post_url).map_err(|_| Error::<T>::InputTooLong)?;
 
-            // Bonds the submitter's balance
-            <<T as Config>::NativeBalance>::hold(&HoldReason::PostBond.into(), &who, bond)?;
+            Self::report(who, bounded)?;
 
-            // Holds the storage rent
-            <<T as Config>::NativeBalance>::hold(&HoldReason::StorageRent.into(), &who, storage_rent.into())?;
+            Ok(())
+        }
 
-            let voting_until = frame_system::Pallet::<T>::block_number() +
-            T::VotingPeriod::get();
+        /// Removes a post, callable by the `ModeratorOrigin`.
+        /// If the post has not been ended, the submitter's bond is slashed if the reports are upheld and released if they are dismissed.
+        /// The post is then ended, and `try_resolve_voting` unfreezes its votes and refunds or slashes its reporters in batches.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If the origin is not the `ModeratorOrigin` ([`DispatchError::BadOrigin`])
+        /// - If they submit nothing for the post_url ([`Error::Empty`])
+        /// - If post input is higher than the `MaxUrlLength` set in the runtime ([`Error::InputTooLong`])
+        /// - If the post does not exist ([`Error::PostDoesNotExist`])
+        /// - If the post has already been removed ([`Error::PostAlreadyRemoved`])
+        #[pallet::call_index(6)]
+        pub fn force_remove_post(
+            origin: OriginFor<T>,
+            post_url: Vec<u8>,
+            decision: ModerationDecision,
+        ) -> DispatchResult {
+            T::ModeratorOrigin::ensure_origin(origin)?;
+            // Ensure the post input is not empty
+            ensure!(!post_url.is_empty(), Error::<T>::Empty);
 
-            // Stores the submitter and bond info
-            Posts::<T>::insert(&id, Post {
-                submitter: who.clone(),
-                bond,
-                bull_votes: Zero::zero(),
-                bear_votes: Zero::zero(),
-                voting_until,
-                ended: false,
-            });
+            // Convert the post input into a bounded vec to use in the actual logic, errors if too long
+            let bounded: BoundedVec<u8, T::MaxUrlLength> = BoundedVec::try_from(post_url).map_err(|_| Error::<T>::InputTooLong)?;
 
-            // Emit an event.
-            Self::deposit_event(Event::PostSubmitted {
-                id,
-                submitter: who,
-                bond, voting_until
-            });
+            Self::remove_post(bounded, decision)?;
 
             Ok(())
         }
 
-        pub(crate) fn submit_vote(
-            who: T::AccountId,
-            post_url: BoundedVec<u8, T::MaxUrlLength>,
-            vote_amount: BalanceOf<T>,
-            direction: Direction,
+        /// Appeals the verdict of a post's voting round, bonding at least `AppealBondCoefficient` of the previous bond.
+        /// Callable by anyone within `AppealPeriod` blocks of the round's voting period ending.
+        /// This opens a new voting round lasting `AppealVotingPeriod`, in which votes must be at least `AppealVoteMinimum`.
+        /// Votes from earlier rounds carry over, and the reward or slash is only applied once the final round is ended.
+        /// The appeal bond is refunded if the final verdict differs from the appealed one, and slashed otherwise.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If they submit nothing for the post_url ([`Error::Empty`])
+        /// - If post input is higher than the `MaxUrlLength` set in the runtime ([`Error::InputTooLong`])
+        /// - If the post does not exist ([`Error::PostDoesNotExist`])
+        /// - If the post has already been ended or removed ([`Error::PostAlreadyEnded`])
+        /// - If the post has been appealed `MaxAppeals` times ([`Error::MaxAppealsReached`])
+        /// - If the vote is still in progress ([`Error::VotingStillOngoing`])
+        /// - If the appeal period has passed ([`Error::AppealWindowClosed`])
+        /// - If the bond is below the required appeal bond ([`Error::AppealBondTooLow`])
+        /// - If the appellant does not have sufficient free tokens for their bond ([`Error::InsufficientFreeBalance`])
+        /// - If voting is paused ([`Error::Paused`])
+        #[pallet::call_index(7)]
+        pub fn appeal_post(
+            origin: OriginFor<T>,
+            post_url: Vec<u8>,
+            bond: BalanceOf<T>,
         ) -> DispatchResult {
-            let id = sp_io::hashing::blake2_256(&post_url);
+            let who = ensure_signed(origin)?;
+            Self::ensure_unpaused(PauseScope::Voting)?;
+            // Ensure the post input is not empty
+            ensure!(!post_url.is_empty(), Error::<T>::Empty);
 
-            // Error if the post does not exist.
-            ensure!(Posts::<T>::contains_key(&id), Error::<T>::PostDoesNotExist);
-            let post_struct = Posts::<T>::get(&id).expect("Already checked that it exists");
-            
-            // Check if voting is still open for that post
-            // If current block number is greater than or equal to the ending period of the post's voting, error.
-            ensure!(frame_system::Pallet::<T>::block_number() < post_struct.voting_until, Error::<T>::VotingEnded);
+            // Convert the post input into a bounded vec to use in the actual logic, errors if too long
+            let bounded: BoundedVec<u8, T::MaxUrlLength> = BoundedVec::try_from(post_url).map_err(|_| Error::<T>::InputTooLong)?;
 
-            // Ensure MaxVoters has not been reached
-            if let Some(voters) = VoteCounts::<T>::get(id) {
-                ensure!(voters != T::MaxVoters::get(), Error::<T>::VotersMaxed)
-            }
+            Self::appeal(who, bounded, bond)?;
 
-            // Check if they have already voted
-            ensure!(!Votes::<T>::contains_key(&who, &id), Error::<T>::AlreadyVoted);
+            Ok(())
+        }
 
-            // Check if they have enough balance for the freeze
-            ensure!(vote_amount < <<T as Config>::NativeBalance>::total_balance(&who), Error::<T>::InsufficientFreeBalance);
+        /// Submits votes on several posts at once, each as a `(post_url, vote_amount, direction)`.
+        /// The votes are applied atomically: if any of them fails, none are applied and the call fails with that vote's error.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If the origin is not the `VoteOrigin` ([`DispatchError::BadOrigin`])
+        /// - If voting is paused ([`Error::Paused`])
+        /// - If the batch has no votes ([`Error::EmptyBatch`])
+        /// - If the batch has more than `MaxBatchSize` votes ([`Error::BatchTooLarge`])
+        /// - If any of the votes fails for one of the reasons listed on `try_submit_vote`
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::try_submit_votes(
+            votes.len() as u32,
+            votes.iter().fold(0u32, |total, (post_url, ..)| total.saturating_add(post_url.len() as u32)),
+        ))]
+        pub fn try_submit_votes(
+            origin: OriginFor<T>,
+            votes: Vec<(Vec<u8>, BalanceOf<T>, Direction)>,
+        ) -> DispatchResult {
+            let who = T::VoteOrigin::ensure_origin(origin)?;
+            Self::ensure_unpaused(PauseScope::Voting)?;
+            ensure!(!votes.is_empty(), Error::<T>::EmptyBatch);
+            ensure!(votes.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
 
-            // Extend_freeze
-            <<T as Config>::NativeBalance>::extend_freeze(&FreezeReason::Vote.into(), &who, vote_amount)?;
+            for (post_url, vote_amount, direction) in votes {
+                ensure!(vote_amount >= T::VoteMinimum::get().into(), Error::<T>::VoteTooLow);
 
-            // Store vote for account and post
-            Votes::<T>::insert(&who, &id, (vote_amount, &direction));
-
-            // Update the list of voters for this post
-            match Voters::<T>::get(id) {
-                None => {
-                    let mut v: BoundedVec<T::AccountId, T::MaxVoters> = BoundedVec::new();
-                    let _ = v.try_push(who.clone());
-                    Voters::<T>::insert(&id, v)
-                },
-                Some(mut v) => {
-                    // Will never error as we already checked regarding the number of voters (vector length) to ensure space
-                    let _ = v.try_push(who.clone());
-                    Voters::<T>::insert(&id, v)
-                },
-            };
+                let bounded = Self::bound_url(post_url)?;
 
-            // Update the number of voters for this post
-            match VoteCounts::<T>::get(id) {
-                None => { VoteCounts::<T>::insert(id, 1) },
-                Some(x) => { VoteCounts::<T>::insert(id, x + 1) },
+                Self::submit_vote(who.clone(), bounded, vote_amount, direction)?;
             }
 
-            // Stores vote info/updates post struct according to vote direction
-            let updated_post_struct = match direction {
-                Direction::Bullish => {
-                    Post {
-                        bull_votes: post_struct.bull_votes + vote_amount,
-                        ..post_struct
-                    }
-                },
-                Direction::Bearish => {
-                    Post {
-                        bear_votes: post_struct.bear_votes + vote_amount,
-                        ..post_struct
-                    }
-                },
-                Direction::Tie => {
-                    post_struct
-                }
-            };
-
-            Posts::<T>::insert(&id, updated_post_struct);
-
-            // Emit an event.
-            Self::deposit_event(Event::VoteSubmitted {
-                id,
-                voter: who,
-                vote_amount,
-                direction,
-            });
-
             Ok(())
         }
 
-        pub(crate) fn update_vote(
-            who: T::AccountId,
-            post_url: BoundedVec<u8, T::MaxUrlLength>,
-            new_vote: BalanceOf<T>,
-            direction: Direction
-        ) -> DispatchResult {
-            let id = sp_io::hashing::blake2_256(&post_url);
+        /// Ends several posts at once, as `try_end_post` does for each.
+        /// Callable by anyone. Posts that cannot be ended are skipped with a [`Event::BatchItemFailed`].
+        /// The caller is paid `KeeperReward` for each post, and pays no fee if every post is ended.
+        /// A batch that ends no post is always charged.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If ending is paused ([`Error::Paused`])
+        /// - If the batch has no posts ([`Error::EmptyBatch`])
+        /// - If the batch has more than `MaxBatchSize` posts ([`Error::BatchTooLarge`])
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::try_end_posts(
+            post_urls.len() as u32,
+            post_urls.iter().fold(0u32, |total, post_url| total.saturating_add(post_url.len() as u32)),
+        ))]
+        #[allow(clippy::useless_conversion)]
+        pub fn try_end_posts(
+            origin: OriginFor<T>,
+            post_urls: Vec<Vec<u8>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_unpaused(PauseScope::Ending)?;
+            ensure!(!post_urls.is_empty(), Error::<T>::EmptyBatch);
+            ensure!(post_urls.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
 
-            // Error if the post does not exist.
-            ensure!(Posts::<T>::contains_key(&id), Error::<T>::PostDoesNotExist);
-            let post_struct = Posts::<T>::get(&id).expect("Already checked that it exists");
+            let mut pays_fee = Pays::No;
+            let mut ended = 0u32;
 
-            // Check if voting is still open for that post
-            // If current block number is greater than or equal to the ending period of the post's voting, error.
-            ensure!(frame_system::Pallet::<T>::block_number() < post_struct.voting_until, Error::<T>::VotingEnded);
+            for (index, post_url) in post_urls.into_iter().enumerate() {
+                // Each post is ended in its own storage layer, so a failure only reverts that post
+                let result = with_storage_layer(|| {
+                    Self::end_post(Some(who.clone()), sp_io::hashing::blake2_256(&Self::bound_url(post_url)?))
+                });
 
-            // Error if this particular vote no longer exists or never existed.
-            ensure!(Votes::<T>::contains_key(&who, &id), Error::<T>::VoteDoesNotExist);
+                match result {
+                    Ok(()) => ended += 1,
+                    Err(error) => {
+                        pays_fee = Pays::Yes;
+                        Self::deposit_event(Event::BatchItemFailed {
+                            index: index as u32,
+                            error,
+                        });
+                    },
+                }
+            }
 
-            // Error if they do not have enough balance for the freeze
-            ensure!(new_vote < <<T as Config>::NativeBalance>::total_balance(&who), Error::<T>::InsufficientFreeBalance);
+            // A batch that ends nothing is always charged
+            if ended == 0 {
+                pays_fee = Pays::Yes;
+            }
+
+            Ok(pays_fee.into())
+        }
+
+        /// Resolves several posts at once, as `try_resolve_voting` does for each.
+        /// Callable by anyone. Posts that cannot be resolved are skipped with a [`Event::BatchItemFailed`].
+        /// The caller is paid `KeeperReward` for each post finished, and pays no fee if every post is finished.
+        /// A batch that finishes no post is always charged.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If ending is paused ([`Error::Paused`])
+        /// - If the batch has no posts ([`Error::EmptyBatch`])
+        /// - If the batch has more than `MaxBatchSize` posts ([`Error::BatchTooLarge`])
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::try_resolve_voting(T::UnfreezeLimit::get(), T::MaxVotesPerAccount::get()).saturating_mul(post_urls.len() as u64))]
+        #[allow(clippy::useless_conversion)]
+        pub fn try_resolve_votings(
+            origin: OriginFor<T>,
+            post_urls: Vec<Vec<u8>>,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::ensure_unpaused(PauseScope::Ending)?;
+            ensure!(!post_urls.is_empty(), Error::<T>::EmptyBatch);
+            ensure!(post_urls.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+            let full_weight = T::WeightInfo::try_resolve_voting(T::UnfreezeLimit::get(), T::MaxVotesPerAccount::get());
+            let mut actual_weight = Weight::zero();
+            let mut pays_fee = Pays::No;
+            let mut finished = 0u32;
+
+            for (index, post_url) in post_urls.into_iter().enumerate() {
+                // Each post is resolved in its own storage layer, so a failure only reverts that post
+                let result = with_storage_layer(|| {
+                    let id = sp_io::hashing::blake2_256(&Self::bound_url(post_url)?);
+                    Self::resolve_post(Some(who.clone()), id).map_err(|e| e.error)
+                });
+
+                match result {
+                    Ok(post_info) => {
+                        actual_weight.saturating_accrue(post_info.actual_weight.unwrap_or(full_weight));
+                        // Partially resolved posts are charged like a partial `try_resolve_voting`
+                        if post_info.pays_fee == Pays::Yes {
+                            pays_fee = Pays::Yes;
+                        } else {
+                            finished += 1;
+                        }
+                    },
+                    Err(error) => {
+                        // Failed items stop early, but are charged in full like a failed `try_resolve_voting`
+                        actual_weight.saturating_accrue(full_weight);
+                        pays_fee = Pays::Yes;
+                        Self::deposit_event(Event::BatchItemFailed {
+                            index: index as u32,
+                            error,
+                        });
+                    },
+                }
+            }
+
+            // A batch that finishes nothing is always charged
+            if finished == 0 {
+                pays_fee = Pays::Yes;
+            }
+
+            Ok((Some(actual_weight), pays_fee).into())
+        }
+
+        /// Ends a post by its ID, as `try_end_post` does. Submitted unsigned by the offchain worker.
+        /// No keeper is paid, so the `KeeperReward` is released to the submitter with the rest of their storage rent.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If the origin is not none ([`DispatchError::BadOrigin`])
+        /// - If the post cannot be ended for one of the reasons listed on `try_end_post`, including ending being paused
+        #[pallet::call_index(11)]
+        // Posts are ended by ID, so there is no URL to hash
+        #[pallet::weight(T::WeightInfo::try_end_post(0))]
+        pub fn auto_end_post(
+            origin: OriginFor<T>,
+            id: [u8; 32],
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            Self::ensure_unpaused(PauseScope::Ending)?;
+
+            Self::end_post(None, id)
+        }
+
+        /// Resolves a post by its ID, as `try_resolve_voting` does. Submitted unsigned by the offchain worker.
+        /// No keeper is paid, so the `KeeperReward` is released to the submitter with the rest of their storage rent.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If the origin is not none ([`DispatchError::BadOrigin`])
+        /// - If the post cannot be resolved for one of the reasons listed on `try_resolve_voting`, including ending being paused
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::try_resolve_voting(T::UnfreezeLimit::get(), T::MaxVotesPerAccount::get()))]
+        #[allow(clippy::useless_conversion)]
+        pub fn auto_resolve_voting(
+            origin: OriginFor<T>,
+            id: [u8; 32],
+        ) -> DispatchResultWithPostInfo {
+            ensure_none(origin)?;
+            Self::ensure_unpaused(PauseScope::Ending)?;
+
+            Self::resolve_post(None, id)
+        }
+
+        /// Stores the result of fetching a post's URL. Submitted unsigned by the offchain worker of one of the `Attesters` when
+        /// `AttestUrls` is enabled, with the attestation signed by its `AuthorityId` key. The signature is checked by
+        /// `validate_unsigned`, both in the transaction pool and when the transaction is included in a block.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If the origin is not none ([`DispatchError::BadOrigin`])
+        /// - If the post does not exist ([`Error::PostDoesNotExist`])
+        /// - If the post's URL has already been attested ([`Error::AlreadyAttested`])
+        #[pallet::call_index(13)]
+        pub fn attest_url(
+            origin: OriginFor<T>,
+            attestation: AttestationPayload<T>,
+            _signature: T::Signature,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+            let AttestationPayload { id, status, content_hash, title, .. } = attestation;
+
+            ensure!(Posts::<T>::contains_key(id), Error::<T>::PostDoesNotExist);
+            ensure!(!Attestations::<T>::contains_key(id), Error::<T>::AlreadyAttested);
+
+            Attestations::<T>::insert(id, Attestation {
+                status,
+                content_hash,
+                title,
+                attested_at: frame_system::Pallet::<T>::block_number(),
+            });
+
+            Self::deposit_event(Event::UrlAttested {
+                id,
+                status,
+                content_hash,
+            });
+
+            Ok(())
+        }
+
+        /// Pauses the calls of `scope`, callable by the `PauseOrigin`. Scopes are paused independently of each other,
+        /// and pausing a scope that is already paused has no effect.
+        /// Reporting, moderating and attesting posts are never paused.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If the origin is not the `PauseOrigin` ([`DispatchError::BadOrigin`])
+        #[pallet::call_index(14)]
+        pub fn pause(
+            origin: OriginFor<T>,
+            scope: PauseScope,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            PalletStatus::<T>::mutate(|status| status.set(scope, true));
+
+            Self::deposit_event(Event::Paused { scope });
+
+            Ok(())
+        }
+
+        /// Unpauses the calls of `scope`, callable by the `PauseOrigin`.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If the origin is not the `PauseOrigin` ([`DispatchError::BadOrigin`])
+        #[pallet::call_index(15)]
+        pub fn unpause(
+            origin: OriginFor<T>,
+            scope: PauseScope,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            PalletStatus::<T>::mutate(|status| status.set(scope, false));
+
+            Self::deposit_event(Event::Unpaused { scope });
+
+            Ok(())
+        }
+
+        /// Ends a post by its ID, callable by root to recover posts that cannot be ended otherwise.
+        /// Unlike `try_end_post` it skips the voting and appeal period checks and ignores pauses, and the verdict can be given
+        /// with `outcome_override` instead of the tallies. Steps of settling the post that fail, such as releasing a hold, are
+        /// logged and skipped rather than failing the call. No keeper is paid, so the `KeeperReward` is released to the submitter.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If the origin is not root ([`DispatchError::BadOrigin`])
+        /// - If the post does not exist ([`Error::PostDoesNotExist`])
+        /// - If the post has already been ended ([`Error::PostAlreadyEnded`])
+        #[pallet::call_index(16)]
+        // Posts are ended by ID, so there is no URL to hash
+        #[pallet::weight(T::WeightInfo::try_end_post(0))]
+        pub fn force_end_post(
+            origin: OriginFor<T>,
+            id: [u8; 32],
+            outcome_override: Option<Direction>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let post_struct = Posts::<T>::get(id).ok_or(Error::<T>::PostDoesNotExist)?;
+            ensure!(!post_struct.is_ended(), Error::<T>::PostAlreadyEnded);
+
+            let overridden = outcome_override.is_some();
+            let result = outcome_override.unwrap_or_else(|| post_struct.tally());
+            // An overridden verdict is not the tallies' doing, so the submitter's own vote cannot have made it Bullish
+            let self_rewarded = !overridden && Self::is_self_rewarded(id, &post_struct);
+
+            let mut failed = 0u32;
+            Self::settle_post(None, id, post_struct, result.clone(), self_rewarded, Some(&mut failed))?;
+
+            Self::deposit_event(Event::PostForceEnded {
+                id,
+                result,
+                overridden,
+                failed,
+            });
+
+            Ok(())
+        }
+
+        /// Resolves a post by its ID, callable by root to recover posts that cannot be resolved otherwise.
+        /// Unlike `try_resolve_voting` it settles up to `max` votes and reports rather than `UnfreezeLimit`, and ignores pauses.
+        /// A vote whose freeze cannot be updated is logged and removed without changing the freeze, and other failed steps, such as
+        /// releasing a report deposit, are logged and skipped rather than failing the call.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If the origin is not root ([`DispatchError::BadOrigin`])
+        /// - If the post does not exist ([`Error::PostDoesNotExist`])
+        /// - If the post is unended ([`Error::PostUnended`])
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::try_resolve_voting(*max, T::MaxVotesPerAccount::get()))]
+        pub fn force_resolve_post(
+            origin: OriginFor<T>,
+            id: [u8; 32],
+            max: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let mut failed = 0u32;
+            let (settled, _) = Self::do_resolve_post(None, id, max, Some(&mut failed))?;
+
+            Self::deposit_event(Event::PostForceResolved {
+                id,
+                settled,
+                failed,
+            });
+
+            Ok(())
+        }
+
+        /// Sets the accounts whose offchain workers may attest post URLs with `attest_url`, callable by root.
+        /// Attestations already stored are kept.
+        ///
+        /// ## Errors
+        ///
+        /// The function will return an error under the following conditions:
+        ///
+        /// - If the origin is not root ([`DispatchError::BadOrigin`])
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::set_attesters(attesters.len() as u32))]
+        pub fn set_attesters(
+            origin: OriginFor<T>,
+            attesters: BoundedVec<T::AccountId, T::MaxAttesters>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Attesters::<T>::put(&attesters);
+
+            Self::deposit_event(Event::AttestersSet { attesters });
+
+            Ok(())
+        }
+    }
+
+    #[pallet::validate_unsigned]
+    impl<T: Config> ValidateUnsigned for Pallet<T> {
+        type Call = Call<T>;
+
+        /// Only accepts `auto_end_post` and `auto_resolve_voting` for posts that can be ended or resolved while ending is not paused, and `attest_url`
+        /// signed by one of the `Attesters` for posts that are not ended or attested yet, providing one tag per post and action so the pool
+        /// keeps a single transaction for each.
+        fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+            let (action, id) = match call {
+                Call::auto_end_post { id } => (KeeperAction::End, id),
+                Call::auto_resolve_voting { id } => (KeeperAction::Resolve, id),
+                Call::attest_url { attestation, signature } => {
+                    // Anyone could claim any result, so attestations are only accepted with the signature of an attester
+                    ensure!(T::AttestUrls::get(), InvalidTransaction::Call);
+                    let attester = attestation.public.clone().into_account();
+                    ensure!(Attesters::<T>::get().contains(&attester), InvalidTransaction::BadSigner);
+                    ensure!(attestation.verify::<T::AuthorityId>(signature.clone()), InvalidTransaction::BadProof);
+                    (KeeperAction::Attest, &attestation.id)
+                },
+                _ => return InvalidTransaction::Call.into(),
+            };
+
+            let post = Posts::<T>::get(id).ok_or(InvalidTransaction::Stale)?;
+            let now = frame_system::Pallet::<T>::block_number();
+            if action == KeeperAction::Attest {
+                ensure!(!post.is_ended() && !Attestations::<T>::contains_key(id), InvalidTransaction::Stale);
+
+                // Gossiped like the keeper transactions, as the attester need not be a block author
+                return ValidTransaction::with_tag_prefix("Bullposting")
+                    .priority(T::UnsignedPriority::get())
+                    .and_provides((action, id))
+                    .longevity(T::UnsignedLongevity::get().saturated_into::<u64>())
+                    .propagate(true)
+                    .build();
+            }
+
+            // The call would fail while ending is paused, the offchain worker submits it again once it is unpaused
+            ensure!(!PalletStatus::<T>::get().is_paused(PauseScope::Ending), InvalidTransaction::Call);
+
+            if post.keeper_action(now) != Some(action) {
+                // An ended post cannot be ended again, anything else has to wait for the post to be ended
+                let error = if post.is_ended() { InvalidTransaction::Stale } else { InvalidTransaction::Future };
+                return error.into();
+            }
+
+            ValidTransaction::with_tag_prefix("Bullposting")
+                .priority(T::UnsignedPriority::get())
+                .and_provides((action, id))
+                .longevity(T::UnsignedLongevity::get().saturated_into::<u64>())
+                .propagate(true)
+                .build()
+        }
+    }
+
+
+    impl<T: Config> Pallet<T> {
+        // Submits `auto_end_post` and `auto_resolve_voting` for up to `MaxBatchSize` posts, returning how many were submitted
+        fn submit_keeper_transactions(now: BlockNumberFor<T>) -> u32 {
+            if PalletStatus::<T>::get().is_paused(PauseScope::Ending) {
+                return 0;
+            }
+
+            let mut submitted = 0u32;
+            for (id, post) in Posts::<T>::iter() {
+                if submitted >= T::MaxBatchSize::get() {
+                    break;
+                }
+
+                let Some(action) = post.keeper_action(now) else {
+                    continue;
+                };
+
+                let call = match action {
+                    KeeperAction::End => Call::auto_end_post { id },
+                    KeeperAction::Resolve => Call::auto_resolve_voting { id },
+                    KeeperAction::Attest => continue,
+                };
+                if Self::submit_unsigned(now, action, id, call) {
+                    submitted += 1;
+                }
+            }
+            submitted
+        }
+
+        // Fetches the URLs of up to `limit` posts that are not ended or attested yet, and submits `attest_url` for each.
+        // The requests are sent together so the whole batch waits for at most one `FETCH_TIMEOUT_MS`.
+        // Only nodes with the `AuthorityId` key of one of the `Attesters` in their keystore fetch anything.
+        fn attest_urls(now: BlockNumberFor<T>, limit: u32) {
+            let attesters = Attesters::<T>::get();
+            let Some(attester) = Signer::<T, T::AuthorityId>::keystore_accounts().find(|account| attesters.contains(&account.id)) else {
+                return;
+            };
+
+            let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(FETCH_TIMEOUT_MS));
+
+            let mut ids = Vec::new();
+            let mut pending = Vec::new();
+            for (id, post) in Posts::<T>::iter() {
+                if ids.len() as u32 >= limit {
+                    break;
+                }
+
+                if post.is_ended() || Attestations::<T>::contains_key(id) || Self::recently_submitted(now, KeeperAction::Attest, id) {
+                    continue;
+                }
+
+                // Posts submitted while offchain indexing was disabled cannot be fetched
+                let Ok(Some(url)) = StorageValueRef::persistent(&url_key(&id)).get::<Vec<u8>>() else {
+                    continue;
+                };
+
+                match core::str::from_utf8(&url).ok().and_then(|url| http::Request::get(url).deadline(deadline).send().ok()) {
+                    Some(request) => {
+                        ids.push(id);
+                        pending.push(request);
+                    },
+                    // URLs that cannot be requested at all are attested as unreachable straight away
+                    None => Self::submit_attestation(now, Self::unreachable_url(id, &attester.public)),
+                }
+            }
+
+            for (id, response) in ids.into_iter().zip(http::PendingRequest::try_wait_all(pending, deadline)) {
+                let attestation = match response {
+                    Ok(Ok(response)) => Self::read_page(id, response, deadline, &attester.public),
+                    _ => None,
+                }.unwrap_or_else(|| Self::unreachable_url(id, &attester.public));
+
+                Self::submit_attestation(now, attestation);
+            }
+        }
+
+        // Reads up to `MAX_FETCH_LEN` bytes of a response into the attestation of the post, `None` if the body cannot be read
+        fn read_page(id: [u8; 32], response: http::Response, deadline: Timestamp, public: &T::Public) -> Option<AttestationPayload<T>> {
+            let mut body = response.body();
+            body.deadline(deadline);
+            let content: Vec<u8> = body.by_ref().take(MAX_FETCH_LEN).collect();
+            if body.error().is_some() {
+                return None;
+            }
+
+            Some(AttestationPayload {
+                id,
+                status: response.code,
+                content_hash: sp_io::hashing::blake2_256(&content),
+                title: Self::page_title(&content),
+                public: public.clone(),
+            })
+        }
+
+        // The attestation of a post whose URL could not be fetched
+        fn unreachable_url(id: [u8; 32], public: &T::Public) -> AttestationPayload<T> {
+            AttestationPayload { id, status: 0, content_hash: [0; 32], title: BoundedVec::new(), public: public.clone() }
+        }
+
+        // Signs the attestation with the attester's key and submits it with `attest_url`
+        fn submit_attestation(now: BlockNumberFor<T>, attestation: AttestationPayload<T>) {
+            let Some(signature) = attestation.sign::<T::AuthorityId>() else {
+                log::warn!(target: LOG_TARGET, "failed to sign the attestation of post {:?}", attestation.id);
+                return;
+            };
+
+            let id = attestation.id;
+            Self::submit_unsigned(now, KeeperAction::Attest, id, Call::attest_url { attestation, signature });
+        }
+
+        /// The trimmed contents of the first `<title>` tag of an HTML page, truncated to `MaxTitleLength`.
+        pub fn page_title(page: &[u8]) -> BoundedVec<u8, T::MaxTitleLength> {
+            let find = |haystack: &[u8], needle: &[u8]| {
+                haystack.windows(needle.len()).position(|window| window.eq_ignore_ascii_case(needle))
+            };
+
+            let title = find(page, b"<title").and_then(|open| {
+                let start = open + page[open..].iter().position(|byte| *byte == b'>')? + 1;
+                let end = start + find(&page[start..], b"</title")?;
+                Some(page[start..end].trim_ascii())
+            }).unwrap_or_default();
+
+            BoundedVec::truncate_from(title.to_vec())
+        }
+
+        // Whether a transaction for the same action on the post was submitted recently enough to still be in the pool
+        fn recently_submitted(now: BlockNumberFor<T>, action: KeeperAction, id: [u8; 32]) -> bool {
+            let key = (OFFCHAIN_SUBMITTED, action, id).encode();
+            match StorageValueRef::persistent(&key).get::<BlockNumberFor<T>>() {
+                Ok(Some(at)) => now < at.saturating_add(T::UnsignedLongevity::get()),
+                _ => false,
+            }
+        }
+
+        // Submits an unsigned transaction for the post unless one was submitted recently, recording the block it was submitted at
+        fn submit_unsigned(now: BlockNumberFor<T>, action: KeeperAction, id: [u8; 32], call: Call<T>) -> bool {
+            if Self::recently_submitted(now, action, id) {
+                return false;
+            }
+
+            let xt = T::create_inherent(call.into());
+            if SubmitTransaction::<T, Call<T>>::submit_transaction(xt).is_err() {
+                return false;
+            }
+
+            StorageValueRef::persistent(&(OFFCHAIN_SUBMITTED, action, id).encode()).set(&now);
+            true
+        }
+
+        // Checks a post input the same way the single post extrinsics do, for use in batches
+        pub(crate) fn bound_url(post_url: Vec<u8>) -> Result<BoundedVec<u8, T::MaxUrlLength>, DispatchError> {
+            // Ensure the post input is not empty
+            ensure!(!post_url.is_empty(), Error::<T>::Empty);
+
+            // Convert the post input into a bounded vec to use in the actual logic, errors if too long
+            Ok(BoundedVec::try_from(post_url).map_err(|_| Error::<T>::InputTooLong)?)
+        }
+
+        // Errors if the `PauseOrigin` paused the calls of `scope`
+        pub(crate) fn ensure_unpaused(scope: PauseScope) -> DispatchResult {
+            ensure!(!PalletStatus::<T>::get().is_paused(scope), Error::<T>::Paused);
+            Ok(())
+        }
+
+        pub(crate) fn submit_post(
+            who: T::AccountId,
+            post_url: BoundedVec<u8, T::MaxUrlLength>,
+            bond: BalanceOf<T>
+        ) -> DispatchResult {
+            let id = sp_io::hashing::blake2_256(&post_url);
+
+            // Checks if the post exists
+            ensure!(!Posts::<T>::contains_key(id), Error::<T>::PostAlreadyExists);
+
+            let submitted_at = frame_system::Pallet::<T>::block_number();
+
+            // Checks the submitter's active posts and the time since their last submission
+            let (active_posts, last_submitted_at) = Submissions::<T>::get(&who).unzip();
+            let active_posts = active_posts.unwrap_or_default();
+            ensure!(active_posts < T::MaxActivePosts::get(), Error::<T>::TooManyActivePosts);
+            if let Some(last_submitted_at) = last_submitted_at {
+                ensure!(submitted_at >= last_submitted_at + T::CooldownPeriod::get(), Error::<T>::SubmissionCooldown);
+            }
+
+            let storage_rent = T::StorageRent::get();
+
+            // Checks if they have enough balance available to be bonded
+            let reduc_bal = <<T as Config>::NativeBalance>::
+            reducible_balance(&who, Preservation::Preserve, Fortitude::Polite);
+            reduc_bal.checked_sub(&bond).ok_or(Error::<T>::InsufficientFreeBalance)?;
+            reduc_bal.checked_sub(&storage_rent.into()).ok_or(Error::<T>::InsufficientFreeBalance)?;
+
+            // Bonds the submitter's balance
+            <<T as Config>::NativeBalance>::hold(&HoldReason::PostBond.into(), &who, bond)?;
+
+            // Holds the storage rent
+            <<T as Config>::NativeBalance>::hold(&HoldReason::StorageRent.into(), &who, storage_rent.into())?;
+
+            let voting_until = submitted_at + T::VotingPeriod::get();
+
+            Submissions::<T>::insert(&who, (active_posts + 1, submitted_at));
+
+            Self::index_url(&id, &post_url);
+
+            // Stores the submitter and bond info
+            Posts::<T>::insert(id, Post {
+                submitter: who.clone(),
+                bond,
+                bull_votes: Zero::zero(),
+                bear_votes: Zero::zero(),
+                submitted_at,
+                voting_until,
+                result: None,
+                rounds: BoundedVec::new(),
+            });
+
+            // Emit an event.
+            Self::deposit_event(Event::PostSubmitted {
+                id,
+                submitter: who,
+                bond, voting_until
+            });
+
+            Ok(())
+        }
+
+        pub(crate) fn submit_vote(
+            who: T::AccountId,
+            post_url: BoundedVec<u8, T::MaxUrlLength>,
+            vote_amount: BalanceOf<T>,
+            direction: Direction,
+        ) -> DispatchResult {
+            let id = sp_io::hashing::blake2_256(&post_url);
+
+            // Error if the post does not exist.
+            ensure!(Posts::<T>::contains_key(id), Error::<T>::PostDoesNotExist);
+            let post_struct = Posts::<T>::get(id).expect("Already checked that it exists");
+            
+            // Check if voting is still open for that post
+            // If current block number is greater than or equal to the ending period of the post's voting, error.
+            ensure!(frame_system::Pallet::<T>::block_number() < post_struct.voting_until, Error::<T>::VotingEnded);
+
+            // Removed posts are ended before their voting period is over
+            ensure!(!post_struct.is_ended(), Error::<T>::VotingEnded);
+
+            // Appeal rounds require larger votes
+            ensure!(!post_struct.is_appealed() || vote_amount >= T::AppealVoteMinimum::get().into(), Error::<T>::VoteTooLow);
+
+            // Check if they have already voted
+            ensure!(!Votes::<T>::contains_key(&who, id), Error::<T>::AlreadyVoted);
+
+            // Check if submitters may vote on their own posts
+            ensure!(post_struct.submitter != who || T::SelfVotePolicy::get() != SelfVotePolicy::Forbid, Error::<T>::SelfVote);
+
+            // Check if they have enough balance for the freeze
+            ensure!(vote_amount < <<T as Config>::NativeBalance>::total_balance(&who), Error::<T>::InsufficientFreeBalance);
+
+            // Errors if they have too many votes that are not yet unfrozen
+            Self::track_vote(&who, None, vote_amount)?;
+
+            // Extend_freeze, or hold the vote as a stake in parimutuel mode
+            if T::Parimutuel::get() {
+                Self::stake_vote(&who, Zero::zero(), vote_amount)?;
+            } else {
+                <<T as Config>::NativeBalance>::extend_freeze(&FreezeReason::Vote.into(), &who, vote_amount)?;
+            }
+
+            // Store vote for account and post
+            Votes::<T>::insert(&who, id, (vote_amount, &direction));
+
+            // Add them to the voters of this post
+            Voters::<T>::insert(id, &who, ());
+
+            // Update the number of voters for this post
+            match VoteCounts::<T>::get(id) {
+                None => { VoteCounts::<T>::insert(id, 1) },
+                Some(x) => { VoteCounts::<T>::insert(id, x + 1) },
+            }
+
+            // Weigh the vote by the voter's record
+            let weight = Self::weigh_vote(&who, id, vote_amount);
+
+            // Stores vote info/updates post struct according to vote direction
+            let updated_post_struct = match direction {
+                Direction::Bullish => {
+                    Post {
+                        bull_votes: post_struct.bull_votes + weight,
+                        ..post_struct
+                    }
+                },
+                Direction::Bearish => {
+                    Post {
+                        bear_votes: post_struct.bear_votes + weight,
+                        ..post_struct
+                    }
+                },
+                Direction::Tie => {
+                    post_struct
+                }
+            };
+
+            Posts::<T>::insert(id, updated_post_struct);
+
+            // Emit an event.
+            Self::deposit_event(Event::VoteSubmitted {
+                id,
+                voter: who,
+                vote_amount,
+                direction,
+            });
+
+            Ok(())
+        }
+
+        pub(crate) fn update_vote(
+            who: T::AccountId,
+            post_url: BoundedVec<u8, T::MaxUrlLength>,
+            new_vote: BalanceOf<T>,
+            direction: Direction
+        ) -> DispatchResult {
+            let id = sp_io::hashing::blake2_256(&post_url);
+
+            // Error if the post does not exist.
+            ensure!(Posts::<T>::contains_key(id), Error::<T>::PostDoesNotExist);
+            let post_struct = Posts::<T>::get(id).expect("Already checked that it exists");
+
+            // Check if voting is still open for that post
+            // If current block number is greater than or equal to the ending period of the post's voting, error.
+            ensure!(frame_system::Pallet::<T>::block_number() < post_struct.voting_until, Error::<T>::VotingEnded);
+
+            // Removed posts are ended before their voting period is over
+            ensure!(!post_struct.is_ended(), Error::<T>::VotingEnded);
+
+            // Appeal rounds require larger votes
+            ensure!(!post_struct.is_appealed() || new_vote >= T::AppealVoteMinimum::get().into(), Error::<T>::VoteTooLow);
+
+            // Error if this particular vote no longer exists or never existed.
+            ensure!(Votes::<T>::contains_key(&who, id), Error::<T>::VoteDoesNotExist);
+
+            // Check if submitters may vote on their own posts, in case the policy changed since they voted
+            ensure!(post_struct.submitter != who || T::SelfVotePolicy::get() != SelfVotePolicy::Forbid, Error::<T>::SelfVote);
+
+            // Error if they do not have enough balance for the freeze
+            ensure!(new_vote < <<T as Config>::NativeBalance>::total_balance(&who), Error::<T>::InsufficientFreeBalance);
+
+            let (previous_amount, previous_direction) = Votes::<T>::take(&who, id);
+
+            // Store vote
+            Votes::<T>::insert(&who, id, (new_vote, &direction));
+            Self::track_vote(&who, Some(previous_amount), new_vote)?;
+
+            // Match the freeze to their largest vote, which may now be smaller than before, or the stake to the new vote
+            if T::Parimutuel::get() {
+                Self::stake_vote(&who, previous_amount, new_vote)?;
+            } else {
+                Self::refreeze(&who)?;
+            }
+
+            // Updates post struct's vote totals according to vote amount and direction
+            // Removes previous directional vote, Tie votes are not counted in either total
+            let previous_weight = VoteWeights::<T>::take(id, &who).unwrap_or(previous_amount);
+            let post_struct = match previous_direction {
+                Direction::Bullish => {
+                    Post {
+                        bull_votes: post_struct.bull_votes - previous_weight,
+                        ..post_struct
+                    }
+                },
+                Direction::Bearish => {
+                    Post {
+                        bear_votes: post_struct.bear_votes - previous_weight,
+                        ..post_struct
+                    }
+                },
+                Direction::Tie => {
+                    post_struct
+                }
+            };
+
+            // Adds new vote, weighed by the voter's current record
+            let weight = Self::weigh_vote(&who, id, new_vote);
+            let updated_post_struct = match direction {
+                Direction::Bullish => {
+                    Post {
+                        bull_votes: post_struct.bull_votes + weight,
+                        ..post_struct
+                    }
+                },
+                Direction::Bearish => {
+                    Post {
+                        bear_votes: post_struct.bear_votes + weight,
+                        ..post_struct
+                    }
+                },
+                Direction::Tie => {
+                    post_struct
+                }
+            };
+
+            Posts::<T>::insert(id, updated_post_struct);
+
+            // Emit an event.
+            Self::deposit_event(Event::VoteUpdated {
+                id,
+                voter: who,
+                vote_amount: new_vote,
+                direction,
+            });
+
+            Ok(())
+        }
+
+        // Ends the post with the given ID, paying the keeper if there is one
+        pub(crate) fn end_post(
+            keeper: Option<T::AccountId>,
+            id: [u8; 32]
+        ) -> DispatchResult {
+
+            // Error if the post does not exist.
+            ensure!(Posts::<T>::contains_key(id), Error::<T>::PostDoesNotExist);
+            let post_struct = Posts::<T>::get(id).expect("Already checked that it exists");
+
+            // Check if the voting period is over for that post
+            // If current block number is lower than the post's voting_until, voting has not ended; error.
+            ensure!(frame_system::Pallet::<T>::block_number() >= post_struct.voting_until, Error::<T>::VotingStillOngoing);
+
+            // Error if the post has already been ended.
+            ensure!(!post_struct.is_ended(), Error::<T>::PostAlreadyEnded);
+
+            // The verdict only takes effect once it can no longer be appealed
+            ensure!(frame_system::Pallet::<T>::block_number() >= post_struct.appealable_until(), Error::<T>::AppealWindowOpen);
+
+            let result = post_struct.tally();
+            let self_rewarded = Self::is_self_rewarded(id, &post_struct);
+
+            Self::settle_post(keeper, id, post_struct, result, self_rewarded, None)
+        }
+
+        // Under `SelfVotePolicy::ExcludeFromReward` the submitter's own vote cannot earn them the reward
+        pub(crate) fn is_self_rewarded(id: [u8; 32], post: &Post<T>) -> bool {
+            let (self_vote, self_vote_direction) = Votes::<T>::get(&post.submitter, id);
+            let self_vote = VoteWeights::<T>::get(id, &post.submitter).unwrap_or(self_vote);
+            T::SelfVotePolicy::get() == SelfVotePolicy::ExcludeFromReward
+                && self_vote_direction == Direction::Bullish
+                && post.bull_votes.saturating_sub(self_vote) <= post.bear_votes
+        }
+
+        // Ends the post with the given verdict, releasing the bond, paying the keeper, settling appeals and rewarding or slashing the submitter.
+        // When `failed` is given the settlement is forced, see `settle_step`.
+        pub(crate) fn settle_post(
+            keeper: Option<T::AccountId>,
+            id: [u8; 32],
+            post_struct: Post<T>,
+            result: Direction,
+            self_rewarded: bool,
+            mut failed: Option<&mut u32>,
+        ) -> DispatchResult {
+            let submitter = post_struct.submitter.clone();
+
+            // Reward/slash amount
+            let bond = post_struct.bond;
+
+            // End the post and update storage
+            let updated_post_struct = Post {
+                result: Some(result.clone()),
+                ..post_struct
+            };
+            Posts::<T>::insert(id, &updated_post_struct);
+
+            // Unlock submitter's bond
+            Self::settle_step(id, failed.as_deref_mut(), || {
+                <<T as Config>::NativeBalance>::release(&HoldReason::PostBond.into(), &submitter, bond, Precision::BestEffort)
+            })?;
+
+            // Pay the caller for ending the post
+            let _ = Self::settle_step(id, failed.as_deref_mut(), || Self::reward_keeper(id, &submitter, keeper))?;
+
+            // Refund appellants whose appeal changed the verdict and slash the others
+            for round in updated_post_struct.rounds.iter() {
+                Self::settle_step(id, failed.as_deref_mut(), || Self::settle_appeal(id, round, round.result != result))?;
+            }
+
+            // Reward/slash submitter or do nothing if there is a tie/no votes
+            let (rewarded, slashed) = match result {
+                Direction::Bullish => {
+                    // Reward the submitter, unless only their own vote made the post Bullish
+                    let rewarded = match (self_rewarded, T::RewardStyle::get()) {
+                        (true, _) => Zero::zero(),
+                        (false, false) => Self::settle_step(id, failed, || Self::reward_flat(&submitter))?,
+                        (false, true) => Self::settle_step(id, failed, || Self::reward_coefficient(&submitter, &bond))?,
+                    };
+                    (rewarded, Zero::zero())
+                },
+                Direction::Bearish => {
+                    // Slashes the submitter
+                    let slashed = match T::SlashStyle::get() {
+                        false => Self::settle_step(id, failed, || Self::slash_flat(&submitter, bond))?,
+                        true => Self::settle_step(id, failed, || Self::slash_coefficient(&submitter, &bond))?,
+                    };
+                    (Zero::zero(), slashed)
+                },
+                // Does nothing if tie/no votes
+                Direction::Tie => (Zero::zero(), Zero::zero()),
+            };
+
+            Self::record_outcome(id, &updated_post_struct, result.clone(), rewarded, slashed);
+
+            Self::deposit_event(Event::PostEnded {
+                id,
+                submitter,
+                result,
+                rewarded,
+                slashed,
+            });
+
+            Ok(())
+        }
+        
+        // Reward a flat amount
+        pub(crate) fn reward_flat(who: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+            let reward = T::FlatReward::get().into();
+
+            // Reward the submitter
+            <<T as Config>::NativeBalance>::mint_into(who, reward)?;
+
+            Ok(reward)
+        }
+        
+        // Reward based on a coefficient and how much they bonded
+        pub(crate) fn reward_coefficient(who: &T::AccountId, bond: &BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+            let reward = Permill::from_percent(T::RewardCoefficient::get()) * *bond;
+
+            // Reward the submitter
+            <<T as Config>::NativeBalance>::mint_into(who, reward)?;
+
+            Ok(reward)
+        }
+
+        // Slash a flat amount
+        pub(crate) fn slash_flat(who: &T::AccountId, bond: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+            let flat_slash = T::FlatSlash::get().into();
+            
+            // Slash the submitter up to their full bond amount, but not beyond
+            if bond < flat_slash {
+                <<T as Config>::NativeBalance>::burn_from(who, bond, Preservation::Protect, Precision::BestEffort, Fortitude::Force)?;
+                Ok(bond)
+            } else {
+                <<T as Config>::NativeBalance>::burn_from(who, flat_slash, Preservation::Protect, Precision::BestEffort, Fortitude::Force)?;
+                Ok(flat_slash)
+            }
+        }
+
+        // Slash based on a coefficient and how much they bonded
+        pub(crate) fn slash_coefficient(who: &T::AccountId, bond: &BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
+            let percent = if T::SlashCoefficient::get() <= 100 {
+                T::SlashCoefficient::get()
+            } else {
+                100
+            };
+            
+            let slash = Percent::from_percent(percent) * *bond;
+            
+            // Slashes the submitter
+            <<T as Config>::NativeBalance>::burn_from(who, slash, Preservation::Protect, Precision::BestEffort, Fortitude::Force)?;
+            
+            Ok(slash)
+        }
+
+        // Resolves the post with the given ID, paying the keeper if there is one and this call finishes resolving it
+        pub(crate) fn resolve_post(
+            keeper: Option<T::AccountId>,
+            id: [u8; 32]
+        ) -> DispatchResultWithPostInfo {
+            let (unfreeze_count, resolved) = Self::do_resolve_post(keeper, id, T::UnfreezeLimit::get(), None)?;
+
+            if resolved {
+                Ok((Some(T::WeightInfo::try_resolve_voting(unfreeze_count, T::MaxVotesPerAccount::get())), Pays::No).into())
+            } else {
+                Ok(().into())
+            }
+        }
+
+        // Unfreezes votes and settles reports of the post up to `limit`, then removes the post once none are left.
+        // Returns how many votes and reports were settled and whether the post was removed.
+        // When `failed` is given the resolution is forced, see `settle_step`.
+        pub(crate) fn do_resolve_post(
+            keeper: Option<T::AccountId>,
+            id: [u8; 32],
+            limit: u32,
+            mut failed: Option<&mut u32>,
+        ) -> Result<(u32, bool), DispatchError> {
+
+            // Error if the post does not exist.
+            ensure!(Posts::<T>::contains_key(id), Error::<T>::PostDoesNotExist);
+            let post_struct = Posts::<T>::get(id).expect("Already checked that it exists");
+
+            // Error if the post is not ended yet
+            ensure!(post_struct.is_ended(), Error::<T>::PostUnended);
+
+            let mut unfreeze_count = 0u32;
+
+            // Call unfreeze_vote() for each voter and remove from `Voters` up to `limit` or until all voters are removed.
+            // Draining removes each voter as it is visited, so the next call carries on from where this one stopped.
+            let mut voters = Voters::<T>::drain_prefix(id);
+            while unfreeze_count < limit {
+                match voters.next() {
+                    Some((voter, ())) => {
+                        match failed.as_deref_mut() {
+                            None => Self::unfreeze_vote(voter, id, &post_struct)?,
+                            Some(failed) => if let Err(error) = with_storage_layer(|| Self::unfreeze_vote(voter.clone(), id, &post_struct)) {
+                                // The voter is already drained, so drop their vote and release it without settling it
+                                log::warn!(target: LOG_TARGET, "forced resolution of post {:?} could not unfreeze the vote of {:?}: {:?}", id, voter, error);
+                                Self::release_vote(&voter, id, &post_struct);
+                                *failed = failed.saturating_add(1);
+                            },
+                        }
+                        unfreeze_count += 1;
+                    },
+                    None => break
+                }
+            }
+
+            // Refund or slash each report deposit once the votes are unfrozen, within the same limit.
+            // Deposits are only slashed if a moderator removed the post and dismissed its reports.
+            let slash_reports = Removals::<T>::get(id) == Some(ModerationDecision::Dismissed);
+            let mut reports = Reports::<T>::drain_prefix(id);
+            while unfreeze_count < limit {
+                match reports.next() {
+                    Some((reporter, deposit)) => {
+                        Self::settle_step(id, failed.as_deref_mut(), || Self::settle_report(id, reporter, deposit, slash_reports))?;
+                        unfreeze_count += 1;
+                    },
+                    None => break
+                }
+            }
+
+            if Voters::<T>::iter_key_prefix(id).next().is_none() && Reports::<T>::iter_key_prefix(id).next().is_none() {
+                // Pay the caller for finishing the resolution, then unlock the rest of the storage rent of the submitter
+                let rent = Self::remaining_rent(&post_struct);
+                let rewarded = Self::settle_step(id, failed.as_deref_mut(), || Self::reward_keeper(id, &post_struct.submitter, keeper))?;
+                Self::settle_step(id, failed, || {
+                    <<T as Config>::NativeBalance>::release(&HoldReason::StorageRent.into(), &post_struct.submitter, rent.saturating_sub(rewarded), Precision::BestEffort)
+                })?;
+
+                // Remove from Posts storage
+                let _ = Posts::<T>::take(id);
+                Removals::<T>::remove(id);
+                Attestations::<T>::remove(id);
+
+                // The post no longer counts towards the submitter's active posts
+                Self::release_submission(&post_struct.submitter);
+
+                // Emit an event
+                Self::deposit_event(Event::PostResolved {
+                    id,
+                });
+                Ok((unfreeze_count, true))
+            } else {
+                Self::deposit_event(Event::PartiallyResolved {
+                    id,
+                });
+                Ok((unfreeze_count, false))
+            }
+        }
+
+        // Runs one step of settling a post. A forced settlement, which has `failed` set, logs a step that fails, rolls back
+        // its changes and counts it in `failed` instead of failing the whole call.
+        pub(crate) fn settle_step<R: Default>(
+            id: [u8; 32],
+            failed: Option<&mut u32>,
+            step: impl FnOnce() -> Result<R, DispatchError>,
+        ) -> Result<R, DispatchError> {
+            let Some(failed) = failed else {
+                return step();
+            };
+
+            with_storage_layer(step).or_else(|error| {
+                log::warn!(target: LOG_TARGET, "forced settlement of post {:?} skipped a failed step: {:?}", id, error);
+                *failed = failed.saturating_add(1);
+                Ok(R::default())
+            })
+        }
+
+        pub(crate) fn report(
+            who: T::AccountId,
+            post_url: BoundedVec<u8, T::MaxUrlLength>
+        ) -> DispatchResult {
+            let id = sp_io::hashing::blake2_256(&post_url);
+
+            // Error if the post does not exist.
+            ensure!(Posts::<T>::contains_key(id), Error::<T>::PostDoesNotExist);
+
+            // Error if a moderator has already removed the post.
+            ensure!(!Removals::<T>::contains_key(id), Error::<T>::PostAlreadyRemoved);
+
+            // Check if they have already reported
+            ensure!(!Reports::<T>::contains_key(id, &who), Error::<T>::AlreadyReported);
+
+            // Check if they have enough free balance for the deposit
+            let deposit: BalanceOf<T> = T::ReportDeposit::get().into();
+            <<T as Config>::NativeBalance>::reducible_balance(&who, Preservation::Preserve, Fortitude::Polite)
+                .checked_sub(&deposit).ok_or(Error::<T>::InsufficientFreeBalance)?;
+
+            // Holds the report deposit
+            <<T as Config>::NativeBalance>::hold(&HoldReason::ReportDeposit.into(), &who, deposit)?;
+
+            Reports::<T>::insert(id, &who, deposit);
+
+            // Emit an event.
+            Self::deposit_event(Event::PostReported {
+                id,
+                reporter: who,
+                deposit,
+            });
+
+            Ok(())
+        }
+
+        pub(crate) fn remove_post(
+            post_url: BoundedVec<u8, T::MaxUrlLength>,
+            decision: ModerationDecision,
+        ) -> DispatchResult {
+            let id = sp_io::hashing::blake2_256(&post_url);
+
+            // Error if the post does not exist.
+            ensure!(Posts::<T>::contains_key(id), Error::<T>::PostDoesNotExist);
+            let post_struct = Posts::<T>::get(id).expect("Already checked that it exists");
+            let submitter = post_struct.submitter.clone();
+
+            // Error if a moderator has already removed the post.
+            ensure!(!Removals::<T>::contains_key(id), Error::<T>::PostAlreadyRemoved);
+
+            // Settle the bond if `end_post` has not already done so, recording the removal as the post's verdict
+            let mut slashed = Zero::zero();
+            let result = match (post_struct.result.clone(), &decision) {
+                (Some(result), _) => result,
+                (None, ModerationDecision::Upheld) => {
+                    slashed = <<T as Config>::NativeBalance>::burn_held(&HoldReason::PostBond.into(), &submitter, post_struct.bond, Precision::BestEffort, Fortitude::Force)?;
+                    Direction::Bearish
+                },
+                (None, ModerationDecision::Dismissed) => {
+                    <<T as Config>::NativeBalance>::release(&HoldReason::PostBond.into(), &submitter, post_struct.bond, Precision::BestEffort)?;
+                    Direction::Tie
+                },
+            };
+
+            // Appeals of a removed post are refunded, unless `end_post` has already settled them.
+            // Nobody is paid for ending it, so the keeper reward `end_post` would have paid is released instead
+            if !post_struct.is_ended() {
+                for round in post_struct.rounds.iter() {
+                    Self::settle_appeal(id, round, true)?;
+                }
+
+                <<T as Config>::NativeBalance>::release(&HoldReason::StorageRent.into(), &submitter, T::KeeperReward::get().into(), Precision::BestEffort)?;
+            }
+
+            // A post that was already ended keeps the outcome `end_post` recorded
+            if !post_struct.is_ended() {
+                Self::record_outcome(id, &post_struct, result.clone(), Zero::zero(), slashed);
+            }
+
+            // End the post so it can no longer be voted on and can be resolved
+            Posts::<T>::insert(id, Post {
+                result: Some(result),
+                ..post_struct
+            });
+            Removals::<T>::insert(id, &decision);
+
+            // Emit an event.
+            Self::deposit_event(Event::PostRemoved {
+                id,
+                submitter,
+                decision,
+                slashed,
+            });
+
+            Ok(())
+        }
+
+        pub(crate) fn appeal(
+            who: T::AccountId,
+            post_url: BoundedVec<u8, T::MaxUrlLength>,
+            bond: BalanceOf<T>,
+        ) -> DispatchResult {
+            let id = sp_io::hashing::blake2_256(&post_url);
+
+            // Error if the post does not exist.
+            ensure!(Posts::<T>::contains_key(id), Error::<T>::PostDoesNotExist);
+            let post_struct = Posts::<T>::get(id).expect("Already checked that it exists");
+
+            // Error if the post has already been ended or removed.
+            ensure!(!post_struct.is_ended(), Error::<T>::PostAlreadyEnded);
+
+            // Error if the post cannot be appealed any further.
+            ensure!((post_struct.rounds.len() as u32) < T::MaxAppeals::get(), Error::<T>::MaxAppealsReached);
+
+            // Appeals are only possible between the end of the round's voting period and the end of its appeal period
+            let now = frame_system::Pallet::<T>::block_number();
+            ensure!(now >= post_struct.voting_until, Error::<T>::VotingStillOngoing);
+            ensure!(now < post_struct.appealable_until(), Error::<T>::AppealWindowClosed);
+
+            // Each appeal must bond more than the round before it
+            let previous_bond = post_struct.rounds.last().map_or(post_struct.bond, |round| round.bond);
+            let minimum_bond = previous_bond.saturating_mul(T::AppealBondCoefficient::get().into()) / 100u32.into();
+            ensure!(bond >= minimum_bond, Error::<T>::AppealBondTooLow);
+
+            // Check if they have enough free balance for the bond
+            <<T as Config>::NativeBalance>::reducible_balance(&who, Preservation::Preserve, Fortitude::Polite)
+                .checked_sub(&bond).ok_or(Error::<T>::InsufficientFreeBalance)?;
+
+            // Bonds the appellant's balance
+            <<T as Config>::NativeBalance>::hold(&HoldReason::AppealBond.into(), &who, bond)?;
+
+            // Record the appealed round and open the next one
+            let mut rounds = post_struct.rounds.clone();
+            rounds.try_push(Round {
+                appellant: who.clone(),
+                bond,
+                bull_votes: post_struct.bull_votes,
+                bear_votes: post_struct.bear_votes,
+                voting_until: post_struct.voting_until,
+                result: post_struct.tally(),
+            }).map_err(|_| Error::<T>::MaxAppealsReached)?;
+            let round = rounds.len() as u32;
+            let voting_until = now + T::AppealVotingPeriod::get();
+
+            Posts::<T>::insert(id, Post {
+                voting_until,
+                rounds,
+                ..post_struct
+            });
+
+            // Emit an event.
+            Self::deposit_event(Event::PostAppealed {
+                id,
+                appellant: who,
+                bond,
+                round,
+                voting_until,
+            });
+
+            Ok(())
+        }
+
+        // Refund or slash the whole bond of an appeal
+        pub(crate) fn settle_appeal(
+            id: [u8; 32],
+            round: &Round<T>,
+            refund: bool,
+        ) -> DispatchResult {
+            let appellant = round.appellant.clone();
+
+            if refund {
+                <<T as Config>::NativeBalance>::release(&HoldReason::AppealBond.into(), &appellant, round.bond, Precision::BestEffort)?;
+
+                Self::deposit_event(Event::AppealSettled {
+                    id,
+                    appellant,
+                    refunded: round.bond,
+                    slashed: Zero::zero(),
+                });
+            } else {
+                let slashed = <<T as Config>::NativeBalance>::burn_held(&HoldReason::AppealBond.into(), &appellant, round.bond, Precision::BestEffort, Fortitude::Force)?;
+
+                Self::deposit_event(Event::AppealSettled {
+                    id,
+                    appellant,
+                    refunded: Zero::zero(),
+                    slashed,
+                });
+            }
+
+            Ok(())
+        }
+
+        pub(crate) fn settle_report(
+            id: [u8; 32],
+            reporter: T::AccountId,
+            deposit: BalanceOf<T>,
+            slash: bool,
+        ) -> DispatchResult {
+            if slash {
+                let slashed = <<T as Config>::NativeBalance>::burn_held(&HoldReason::ReportDeposit.into(), &reporter, deposit, Precision::BestEffort, Fortitude::Force)?;
+
+                Self::deposit_event(Event::ReportSlashed {
+                    id,
+                    reporter,
+                    deposit: slashed,
+                });
+            } else {
+                <<T as Config>::NativeBalance>::release(&HoldReason::ReportDeposit.into(), &reporter, deposit, Precision::BestEffort)?;
+
+                Self::deposit_event(Event::ReportRefunded {
+                    id,
+                    reporter,
+                    deposit,
+                });
+            }
+
+            Ok(())
+        }
+
+        pub(crate) fn unfreeze_vote(
+            who: T::AccountId,
+            id: [u8; 32],
+            post: &Post<T>,
+        ) -> DispatchResult {
+            let (amount, direction) = Self::remove_vote(&who, id);
+            Self::record_vote(&who, post, &direction);
+
+            if T::Parimutuel::get() {
+                return Self::settle_stake(id, who, post, amount, direction);
+            }
+
+            // Shrink the freeze to their largest remaining vote, or remove it if this was their last one
+            Self::refreeze(&who)?;
+
+            // Emit an event
+            Self::deposit_event(Event::VoteUnfrozen {
+                id,
+                account: who,
+                amount,
+            });
 
-            let (previous_amount, previous_direction) = Votes::<T>::take(&who, &id);
+            Ok(())
+        }
 
-            // Extend_freeze
-            <<T as Config>::NativeBalance>::extend_freeze(&FreezeReason::Vote.into(), &who, new_vote)?;
+        // Remove a vote that could not be unfrozen, refunding its stake in parimutuel mode or shrinking the freeze to the
+        // account's remaining votes, so nothing stays frozen or held for a vote that is gone
+        pub(crate) fn release_vote(who: &T::AccountId, id: [u8; 32], post: &Post<T>) {
+            let (amount, direction) = Self::remove_vote(who, id);
+            Self::record_vote(who, post, &direction);
 
-            // Store vote
-            Votes::<T>::insert(&who, &id, (new_vote, &direction));
+            let result = if T::Parimutuel::get() {
+                <<T as Config>::NativeBalance>::release(&HoldReason::VoteStake.into(), who, amount, Precision::BestEffort).map(|_| ())
+            } else {
+                // Thawing cannot fail, so the freeze is removed outright if it cannot be shrunk
+                Self::refreeze(who).or_else(|_| <<T as Config>::NativeBalance>::thaw(&FreezeReason::Vote.into(), who))
+            };
 
-            // Updates post struct's vote totals according to vote amount and direction
-            // Removes previous directional vote and adds new vote
-            let updated_post_struct = match direction {
-                Direction::Bullish => {
-                    if previous_direction == Direction::Bullish {
-                        Post {
-                            bull_votes: post_struct.bull_votes - previous_amount + new_vote,
-                            ..post_struct
-                        }
-                    } else {
-                        Post {
-                            bull_votes: post_struct.bull_votes + new_vote,
-                            bear_votes: post_struct.bear_votes - previous_amount,
-                            ..post_struct
-                        }
-                    }
-                },
-                Direction::Bearish => {
-                    if previous_direction == Direction::Bearish {
-                        Post {
-                            bear_votes: post_struct.bear_votes - previous_amount + new_vote,
-                            ..post_struct
-                        }
-                    } else {
-                        Post {
-                            bull_votes: post_struct.bull_votes - previous_amount,
-                            bear_votes: post_struct.bear_votes + new_vote,
-                            ..post_struct
-                        }
-                    }
-                },
-                Direction::Tie => {
-                    if previous_direction == Direction::Bullish {
-                        Post {
-                            bull_votes: post_struct.bull_votes - previous_amount,
-                            ..post_struct
-                        }
-                    } else {
-                        Post {
-                            bear_votes: post_struct.bear_votes - previous_amount,
-                            ..post_struct
-                        }
-                    }
-                }
+            if let Err(error) = result {
+                log::warn!(target: LOG_TARGET, "could not release the vote of {:?} on post {:?}: {:?}", who, id, error);
+            }
+        }
+
+        // Settle a parimutuel stake by the post's verdict. Winners get their stake back with their share of the losing side's
+        // stakes less `ParimutuelFee`, pro rata to their stake, and losers' stakes are burned. If the post ended in a tie, was
+        // removed by a moderator or nobody staked on the winning side, or the voter voted Tie, the stake is refunded.
+        // The verdict is fixed by `end_post`, but stakes are settled here, one voter at a time, so that settling a post with
+        // more voters than `UnfreezeLimit` is spread over several `try_resolve_voting` calls like unfreezing votes is
+        pub(crate) fn settle_stake(
+            id: [u8; 32],
+            voter: T::AccountId,
+            post: &Post<T>,
+            stake: BalanceOf<T>,
+            direction: Direction,
+        ) -> DispatchResult {
+            let (winning, losing) = match post.result {
+                // A removal is the moderator's decision rather than the market's verdict
+                _ if Removals::<T>::contains_key(id) => (Zero::zero(), Zero::zero()),
+                Some(Direction::Bullish) => (post.bull_votes, post.bear_votes),
+                Some(Direction::Bearish) => (post.bear_votes, post.bull_votes),
+                _ => (Zero::zero(), Zero::zero()),
             };
 
-            Posts::<T>::insert(&id, updated_post_struct);
+            let mut winnings = Zero::zero();
+            let mut lost = Zero::zero();
+
+            if winning.is_zero() || direction == Direction::Tie {
+                <<T as Config>::NativeBalance>::release(&HoldReason::VoteStake.into(), &voter, stake, Precision::BestEffort)?;
+            } else if Some(&direction) == post.result.as_ref() {
+                let pot = losing.saturating_sub(T::ParimutuelFee::get() * losing);
+                winnings = helpers_128bit::multiply_by_rational_with_rounding(
+                    stake.saturated_into(),
+                    pot.saturated_into(),
+                    winning.saturated_into(),
+                    Rounding::Down,
+                ).map(SaturatedConversion::saturated_into).unwrap_or_else(Zero::zero);
+
+                <<T as Config>::NativeBalance>::release(&HoldReason::VoteStake.into(), &voter, stake, Precision::BestEffort)?;
+                <<T as Config>::NativeBalance>::mint_into(&voter, winnings)?;
+            } else {
+                lost = <<T as Config>::NativeBalance>::burn_held(&HoldReason::VoteStake.into(), &voter, stake, Precision::BestEffort, Fortitude::Force)?;
+            }
 
-            // Emit an event.
-            Self::deposit_event(Event::VoteUpdated {
+            Self::deposit_event(Event::StakeSettled {
                 id,
-                voter: who,
-                vote_amount: new_vote,
-                direction,
+                voter,
+                stake,
+                winnings,
+                lost,
             });
 
             Ok(())
         }
 
-        pub(crate) fn end_post(
-            post_url: BoundedVec<u8, T::MaxUrlLength>
-        ) -> DispatchResult {
-            let id = sp_io::hashing::blake2_256(&post_url);
+        // The weight a vote counts towards the tallies with, scaled by the voter's accuracy within `AccuracyWeight` of its size.
+        // Voters without a record have no accuracy, or they could vote from new accounts to shed a poor record. Stored in `VoteWeights` if it differs from the vote's size, so the vote can later be taken out of the tallies
+        pub(crate) fn weigh_vote(who: &T::AccountId, id: [u8; 32], amount: BalanceOf<T>) -> BalanceOf<T> {
+            let spread = T::AccuracyWeight::get() * amount;
+            let accuracy = VoterRecords::<T>::get(who).accuracy();
+            let weight = amount.saturating_sub(spread).saturating_add(accuracy * spread.saturating_mul(2u32.into()));
 
-            // Error if the post does not exist.
-            ensure!(Posts::<T>::contains_key(&id), Error::<T>::PostDoesNotExist);
-            let post_struct = Posts::<T>::get(&id).expect("Already checked that it exists");
-            let submitter = post_struct.submitter.clone();
+            if weight != amount {
+                VoteWeights::<T>::insert(id, who, weight);
+            }
 
-            // Check if the voting period is over for that post
-            // If current block number is lower than the post's voting_until, voting has not ended; error.
-            ensure!(frame_system::Pallet::<T>::block_number() >= post_struct.voting_until, Error::<T>::VotingStillOngoing);
+            weight
+        }
 
-            // Error if the post has already been ended.
-            ensure!(!post_struct.ended, Error::<T>::PostAlreadyEnded);
+        // Count a resolved vote in the voter's record, and when to prune the record if it is not kept forever
+        pub(crate) fn record_vote(who: &T::AccountId, post: &Post<T>, direction: &Direction) {
+            let now = frame_system::Pallet::<T>::block_number();
 
-            // End the post and update storage
-            let updated_post_struct = Post {
-                ended: true,
-                ..post_struct
+            VoterRecords::<T>::mutate(who, |record| {
+                match post.result.as_ref() {
+                    Some(Direction::Tie) | None => record.ties = record.ties.saturating_add(1),
+                    Some(result) if result == direction => record.correct = record.correct.saturating_add(1),
+                    Some(_) => record.incorrect = record.incorrect.saturating_add(1),
+                }
+                record.resolved_at = now;
+            });
+
+            let retention = T::OutcomeRetention::get();
+            if !retention.is_zero() {
+                VoterRecordExpiries::<T>::insert(now.saturating_add(retention), who, ());
+            }
+        }
+
+        // Hold or release the difference between a parimutuel vote's `previous` stake and its new `amount`
+        pub(crate) fn stake_vote(who: &T::AccountId, previous: BalanceOf<T>, amount: BalanceOf<T>) -> DispatchResult {
+            if amount > previous {
+                let extra = amount - previous;
+                <<T as Config>::NativeBalance>::reducible_balance(who, Preservation::Preserve, Fortitude::Polite)
+                    .checked_sub(&extra).ok_or(Error::<T>::InsufficientFreeBalance)?;
+                <<T as Config>::NativeBalance>::hold(&HoldReason::VoteStake.into(), who, extra)
+            } else {
+                <<T as Config>::NativeBalance>::release(&HoldReason::VoteStake.into(), who, previous - amount, Precision::Exact).map(|_| ())
+            }
+        }
+
+        // Remove the vote from Votes and VoteCounts without touching the freeze or stake, returning the vote
+        pub(crate) fn remove_vote(who: &T::AccountId, id: [u8; 32]) -> (BalanceOf<T>, Direction) {
+            let (amount, direction) = Votes::<T>::take(who, id);
+            VoteWeights::<T>::remove(id, who);
+            Self::untrack_vote(who, amount);
+
+            // Decrease vote count or remove if 0
+            if let Some(count) = VoteCounts::<T>::get(id) {
+                if count > 1 {
+                    VoteCounts::<T>::insert(id, count - 1)
+                } else {
+                    VoteCounts::<T>::remove(id)
+                }
             };
-            Posts::<T>::insert(&id, &updated_post_struct);
 
-            // Reward/slash amount
-            let bond = post_struct.bond;
+            (amount, direction)
+        }
 
-            // Unlock submitter's bond
-            <<T as Config>::NativeBalance>::release(&HoldReason::PostBond.into(), &submitter, bond, Precision::BestEffort)?;
+        // Transfer `KeeperReward` from the submitter's storage rent to the account that ended or resolved their post.
+        // Posts ended or resolved by the offchain worker have no keeper, so the reward is released to the submitter instead.
+        pub(crate) fn reward_keeper(
+            id: [u8; 32],
+            submitter: &T::AccountId,
+            keeper: Option<T::AccountId>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let reward: BalanceOf<T> = T::KeeperReward::get().into();
+            if reward.is_zero() {
+                return Ok(reward);
+            }
 
-            let result: Direction = if updated_post_struct.bull_votes > updated_post_struct.bear_votes {
-                Direction::Bullish
-            } else if updated_post_struct.bull_votes < updated_post_struct.bear_votes {
-                Direction::Bearish
-            } else {
-                Direction::Tie
+            let Some(keeper) = keeper else {
+                return <<T as Config>::NativeBalance>::release(&HoldReason::StorageRent.into(), submitter, reward, Precision::BestEffort);
             };
 
-            // Reward/slash submitter or do nothing if there is a tie/no votes
-            if result == Direction::Bullish {
-                // Reward the submitter
-                let rewarded = match T::RewardStyle::get() {
-                    false => Self::reward_flat(&submitter)?,
-                    true => Self::reward_coefficient(&submitter, &bond)?,
-                };
+            let amount = <<T as Config>::NativeBalance>::transfer_on_hold(&HoldReason::StorageRent.into(), submitter, &keeper, reward, Precision::BestEffort, Restriction::Free, Fortitude::Force)?;
 
-                Self::deposit_event(Event::PostEnded { 
-                    id,
-                    submitter,
-                    result,
-                    rewarded,
-                    slashed: Zero::zero(),
-                });
-            } else if result == Direction::Bearish {
-                // Slashes the submitter
-                let slashed = match T::SlashStyle::get() {
-                    false => Self::slash_flat(&submitter, bond)?,
-                    true => Self::slash_coefficient(&submitter, &bond)?,
-                };
+            Self::deposit_event(Event::KeeperRewarded {
+                id,
+                keeper,
+                amount,
+            });
 
-                Self::deposit_event(Event::PostEnded { 
-                    id,
-                    submitter,
-                    result,
-                    rewarded: Zero::zero(),
-                    slashed,
-                });
+            Ok(amount)
+        }
+
+        // The storage rent still held for a post, less the keeper reward for ending it once it is ended
+        pub(crate) fn remaining_rent(post: &Post<T>) -> BalanceOf<T> {
+            let rent: BalanceOf<T> = T::StorageRent::get().into();
+            if post.is_ended() {
+                rent.saturating_sub(T::KeeperReward::get().into())
             } else {
-                // Does nothing if tie/no votes
-                Self::deposit_event(Event::PostEnded { 
-                    id,
-                    submitter,
-                    result: Direction::Tie,
-                    rewarded: Zero::zero(),
-                    slashed: Zero::zero(),
-                });
+                rent
             }
+        }
 
-            Ok(())
+        // Record the outcome of a post as it is ended, and when to prune it if outcomes are not kept forever
+        pub(crate) fn record_outcome(
+            id: [u8; 32],
+            post: &Post<T>,
+            result: Direction,
+            rewarded: BalanceOf<T>,
+            slashed: BalanceOf<T>,
+        ) {
+            let now = frame_system::Pallet::<T>::block_number();
+
+            PostOutcomes::<T>::insert(id, PostOutcome {
+                submitter: post.submitter.clone(),
+                bull_votes: post.bull_votes,
+                bear_votes: post.bear_votes,
+                result,
+                rewarded,
+                slashed,
+                ended_at: now,
+            });
+
+            let retention = T::OutcomeRetention::get();
+            if !retention.is_zero() {
+                OutcomeExpiries::<T>::insert(now.saturating_add(retention), id, ());
+            }
         }
-        
-        // Reward a flat amount
-        pub(crate) fn reward_flat(who: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
-            let reward = T::FlatReward::get().into();
 
-            // Reward the submitter
-            <<T as Config>::NativeBalance>::mint_into(&who, reward)?;
+        // Index the URL for the offchain worker, as only its hash is kept on chain
+        pub(crate) fn index_url(id: &[u8; 32], post_url: &BoundedVec<u8, T::MaxUrlLength>) {
+            sp_io::offchain_index::set(&url_key(id), &post_url.encode());
+        }
 
-            Ok(reward)
+        // Index the URLs of the genesis posts, which could not be indexed while the genesis state was built
+        pub(crate) fn index_genesis_urls() -> Weight {
+            let mut count = 0u64;
+
+            for (id, post_url) in GenesisUrls::<T>::drain() {
+                Self::index_url(&id, &post_url);
+                count += 1;
+            }
+
+            T::DbWeight::get().reads_writes(count + 1, count)
         }
-        
-        // Reward based on a coefficient and how much they bonded
-        pub(crate) fn reward_coefficient(who: &T::AccountId, bond: &BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
-            let reward = Permill::from_percent(T::RewardCoefficient::get()) * *bond;
 
-            // Reward the submitter
-            <<T as Config>::NativeBalance>::mint_into(&who, reward)?;
+        // Prune the outcomes queued for block `now`. An outcome is kept if its post was ended again since it was queued,
+        // or if it has not outlived the current `OutcomeRetention`
+        pub(crate) fn prune_outcomes(now: BlockNumberFor<T>) -> Weight {
+            let retention = T::OutcomeRetention::get();
+            let mut count = 0u32;
+
+            for (id, ()) in OutcomeExpiries::<T>::drain_prefix(now) {
+                PostOutcomes::<T>::mutate_exists(id, |outcome| {
+                    let expired = outcome.as_ref().is_some_and(|outcome| {
+                        !retention.is_zero() && outcome.ended_at.saturating_add(retention) <= now
+                    });
+                    if expired {
+                        *outcome = None;
+                    }
+                });
+                count += 1;
+            }
 
-            Ok(reward)
+            T::WeightInfo::prune_outcomes(count)
         }
 
-        // Slash a flat amount
-        pub(crate) fn slash_flat(who: &T::AccountId, bond: BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
-            let flat_slash = T::FlatSlash::get().into();
-            
-            // Slash the submitter up to their full bond amount, but not beyond
-            if bond < flat_slash {
-                <<T as Config>::NativeBalance>::burn_from(&who, bond, Preservation::Protect, Precision::BestEffort, Fortitude::Force)?;
-                Ok(bond)
-            } else {
-                <<T as Config>::NativeBalance>::burn_from(&who, flat_slash, Preservation::Protect, Precision::BestEffort, Fortitude::Force)?;
-                Ok(flat_slash)
+        // Prune the voter records queued for block `now`. A record is kept if a vote of its account was resolved since it was
+        // queued, or if it has not outlived the current `OutcomeRetention`
+        pub(crate) fn prune_voter_records(now: BlockNumberFor<T>) -> Weight {
+            let retention = T::OutcomeRetention::get();
+            let mut count = 0u32;
+
+            for (who, ()) in VoterRecordExpiries::<T>::drain_prefix(now) {
+                VoterRecords::<T>::mutate_exists(who, |record| {
+                    let expired = record.as_ref().is_some_and(|record| {
+                        !retention.is_zero() && record.resolved_at.saturating_add(retention) <= now
+                    });
+                    if expired {
+                        *record = None;
+                    }
+                });
+                count += 1;
             }
+
+            T::WeightInfo::prune_voter_records(count)
         }
 
-        // Slash based on a coefficient and how much they bonded
-        pub(crate) fn slash_coefficient(who: &T::AccountId, bond: &BalanceOf<T>) -> Result<BalanceOf<T>, DispatchError> {
-            let percent = if T::SlashCoefficient::get() <= 100 {
-                T::SlashCoefficient::get()
-            } else {
-                100
-            };
-            
-            let slash = Percent::from_percent(percent) * *bond;
-            
-            // Slashes the submitter
-            <<T as Config>::NativeBalance>::burn_from(&who, slash, Preservation::Protect, Precision::BestEffort, Fortitude::Force)?;
-            
-            Ok(slash)
+        // Decrease the submitter's active posts, removing their entry once it is no longer needed for the cooldown
+        pub(crate) fn release_submission(who: &T::AccountId) {
+            Submissions::<T>::mutate_exists(who, |submission| {
+                if let Some((active_posts, last_submitted_at)) = submission {
+                    *active_posts = active_posts.saturating_sub(1);
+
+                    let now = frame_system::Pallet::<T>::block_number();
+                    if *active_posts == 0 && now >= *last_submitted_at + T::CooldownPeriod::get() {
+                        *submission = None;
+                    }
+                }
+            });
         }
 
-        pub(crate) fn resolve_post(
-            post_url: BoundedVec<u8, T::MaxUrlLength>
-        ) -> DispatchResultWithPostInfo {
-            let id = sp_io::hashing::blake2_256(&post_url);
+        // Add a vote of `amount` to the account's `VoteAmounts`, in place of a vote of `previous` if it was updated
+        pub(crate) fn track_vote(who: &T::AccountId, previous: Option<BalanceOf<T>>, amount: BalanceOf<T>) -> DispatchResult {
+            VoteAmounts::<T>::try_mutate(who, |amounts| {
+                if let Some(index) = previous.and_then(|previous| amounts.iter().position(|tracked| *tracked == previous)) {
+                    amounts.swap_remove(index);
+                }
+                amounts.try_push(amount).map_err(|_| Error::<T>::TooManyVotes.into())
+            })
+        }
 
-            // Error if the post does not exist.
-            ensure!(Posts::<T>::contains_key(&id), Error::<T>::PostDoesNotExist);
-            let post_struct = Posts::<T>::get(&id).expect("Already checked that it exists");
+        // Remove a vote of `amount` from the account's `VoteAmounts`, and the entry with its last vote
+        pub(crate) fn untrack_vote(who: &T::AccountId, amount: BalanceOf<T>) {
+            VoteAmounts::<T>::mutate_exists(who, |amounts| {
+                if let Some(tracked) = amounts {
+                    if let Some(index) = tracked.iter().position(|tracked| *tracked == amount) {
+                        tracked.swap_remove(index);
+                    }
+                    if tracked.is_empty() {
+                        *amounts = None;
+                    }
+                }
+            });
+        }
 
-            // Error if the post is not ended yet
-            ensure!(post_struct.ended, Error::<T>::PostUnended);
+        // Votes on different posts share one freeze, so it is kept at the size of the account's largest vote
+        pub(crate) fn refreeze(who: &T::AccountId) -> DispatchResult {
+            let largest = VoteAmounts::<T>::get(who).into_iter().max();
 
-            let mut unfreeze_count = 0u32;
+            match largest {
+                Some(amount) => <<T as Config>::NativeBalance>::set_freeze(&FreezeReason::Vote.into(), who, amount),
+                None => <<T as Config>::NativeBalance>::thaw(&FreezeReason::Vote.into(), who),
+            }
+        }
+    }
+
+    #[cfg(any(test, feature = "try-runtime", feature = "fuzzing"))]
+    impl<T: Config> Pallet<T> {
+        /// Checks the invariants of the pallet's storage and of the balances it holds and freezes.
+        ///
+        /// ## Tallies
+        ///
+        /// - `VoteCounts` of a post equals the number of its `Voters`, and every voter has a `Votes` entry.
+        /// - The `Votes` of an unended post add up to its `bull_votes` and `bear_votes`. Ended posts are
+        ///   resolved in batches, so their remaining votes may add up to less.
+        /// - No `Votes`, `VoteCounts`, `Reports` or `Removals` entry exists without its post, and removed posts are ended.
+        /// - The active posts in a submitter's `Submissions` entry equal the number of their posts.
+        ///
+        /// ## Holds
+        ///
+        /// - A submitter's `PostBond` hold equals the bonds of their unended posts.
+        /// - A submitter's `StorageRent` hold equals `StorageRent` for each of their posts still in storage,
+        ///   less `KeeperReward` for each of them that has been ended.
+        /// - A reporter's `ReportDeposit` hold equals their deposits on posts still in storage.
+        /// - An appellant's `AppealBond` hold equals their bonds on unended posts.
+        ///
+        /// ## Freezes
+        ///
+        /// - A voter's `VoteAmounts` entry holds the amounts of their votes that have not been unfrozen yet.
+        /// - A voter's `Vote` freeze equals their largest vote that has not been unfrozen yet, or their `VoteStake`
+        ///   hold equals the sum of those votes in parimutuel mode. Accounts without such votes have neither.
+        pub fn do_try_state() -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            Self::try_state_tallies()?;
+            Self::try_state_holds()?;
+            Self::try_state_freezes()
+        }
 
-            // Assume all will be unfrozen
-            let mut all_unfrozen = true;
-
-            // Call unfreeze_vote() for each voter and remove from `Voters` up to `UnfreezeLimit` or until all voters are removed
-            if let Some(mut voters) = Voters::<T>::take(id) {
-                while !(unfreeze_count >= T::UnfreezeLimit::get()) {
-                    match voters.pop() {
-                        Some(voter) => {
-                            Self::unfreeze_vote(voter, id)?;
-                            unfreeze_count += 1;
-                        },
-                        None => break
+        fn try_state_tallies() -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            use scale_info::prelude::collections::BTreeMap;
+
+            for (id, post) in Posts::<T>::iter() {
+                let mut voters = 0u32;
+                let mut bull_votes: BalanceOf<T> = Zero::zero();
+                let mut bear_votes: BalanceOf<T> = Zero::zero();
+
+                for voter in Voters::<T>::iter_key_prefix(id) {
+                    ensure!(Votes::<T>::contains_key(&voter, id), "A voter on a post has no vote");
+                    voters += 1;
+
+                    let (amount, direction) = Votes::<T>::get(&voter, id);
+                    let amount = VoteWeights::<T>::get(id, &voter).unwrap_or(amount);
+                    match direction {
+                        Direction::Bullish => bull_votes += amount,
+                        Direction::Bearish => bear_votes += amount,
+                        Direction::Tie => {},
                     }
                 }
-                if unfreeze_count >= T::UnfreezeLimit::get() {
-                    all_unfrozen = false;
-                    Voters::<T>::insert(id, voters);
+
+                ensure!(VoteCounts::<T>::get(id).unwrap_or_default() == voters, "VoteCounts does not match the number of voters");
+
+                if post.is_ended() {
+                    ensure!(bull_votes <= post.bull_votes && bear_votes <= post.bear_votes, "Remaining votes exceed the final tallies");
+                } else {
+                    ensure!(bull_votes == post.bull_votes && bear_votes == post.bear_votes, "Votes do not add up to the post's tallies");
                 }
             }
 
-            if all_unfrozen {
-                // Unlock the storage rent of the submitter
-                <<T as Config>::NativeBalance>::release(&HoldReason::StorageRent.into(), &post_struct.submitter, T::StorageRent::get().into(), Precision::BestEffort)?;
+            for (voter, id, _) in Votes::<T>::iter() {
+                ensure!(Posts::<T>::contains_key(id), "A vote exists for a post that does not");
+                ensure!(Voters::<T>::contains_key(id, &voter), "A vote is missing from the post's voters");
+            }
 
-                // Remove from Posts storage
-                let _ = Posts::<T>::take(id);
+            for (id, voter) in VoteWeights::<T>::iter_keys() {
+                ensure!(Votes::<T>::contains_key(&voter, id), "A vote weight exists for a vote that does not");
+            }
 
-                // Emit an event
-                Self::deposit_event(Event::PostResolved {
-                    id,
-                });
-                Ok(Some(T::WeightInfo::try_resolve_voting(unfreeze_count)).into())
-            } else {
-                Self::deposit_event(Event::PartiallyResolved {
-                    id,
-                });
-                Ok(().into())
+            for id in VoteCounts::<T>::iter_keys() {
+                ensure!(Posts::<T>::contains_key(id), "A vote count exists for a post that does not");
+            }
+
+            for (id, _) in Reports::<T>::iter_keys() {
+                ensure!(Posts::<T>::contains_key(id), "A report exists for a post that does not");
+            }
+
+            for id in Removals::<T>::iter_keys() {
+                let post = Posts::<T>::get(id).ok_or("A removal exists for a post that does not")?;
+                ensure!(post.is_ended(), "A removed post has not been ended");
+            }
+
+            for id in Attestations::<T>::iter_keys() {
+                ensure!(Posts::<T>::contains_key(id), "An attestation exists for a post that does not");
+            }
+
+            let mut submitters: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+            for (_id, post) in Posts::<T>::iter() {
+                *submitters.entry(post.submitter).or_insert(0) += 1;
+            }
+
+            for (submitter, (active_posts, _)) in Submissions::<T>::iter() {
+                ensure!(submitters.remove(&submitter).unwrap_or_default() == active_posts, "Active posts do not match the submitter's posts");
             }
+            ensure!(submitters.is_empty(), "A submitter of a post has no Submissions entry");
+
+            Ok(())
         }
 
-        pub(crate) fn unfreeze_vote(
-            who: T::AccountId,
-            id: [u8; 32]
-        ) -> DispatchResult {
-            // Remove from Votes and get vote amount
-            let (amount, _direction) = Votes::<T>::take(&who, id);
+        fn try_state_holds() -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            use frame_support::traits::fungible::InspectHold;
+            use scale_info::prelude::collections::BTreeMap;
 
-            // Remove freeze
-            <<T as Config>::NativeBalance>::decrease_frozen(&FreezeReason::Vote.into(), &who, amount.clone())?;
+            let mut submitters: BTreeMap<T::AccountId, (BalanceOf<T>, BalanceOf<T>)> = BTreeMap::new();
 
-            // Decrease vote count or remove if 0
-            if let Some(count) = VoteCounts::<T>::get(id) {
-                if count > 1 {
-                    VoteCounts::<T>::insert(id, count - 1)
-                } else {
-                    VoteCounts::<T>::remove(id)
+            for (_id, post) in Posts::<T>::iter() {
+                let (bonded, rent) = submitters.entry(post.submitter.clone()).or_insert((Zero::zero(), Zero::zero()));
+                if !post.is_ended() {
+                    *bonded += post.bond;
                 }
-            };
+                *rent += Self::remaining_rent(&post);
+            }
 
-            // Emit an event
-            Self::deposit_event(Event::VoteUnfrozen {
-                id,
-                account: who,
-                amount,
-            });
+            for (submitter, (bonded, rent)) in submitters {
+                ensure!(
+                    <<T as Config>::NativeBalance>::balance_on_hold(&HoldReason::PostBond.into(), &submitter) == bonded,
+                    "PostBond hold does not match the submitter's unended posts"
+                );
+                ensure!(
+                    <<T as Config>::NativeBalance>::balance_on_hold(&HoldReason::StorageRent.into(), &submitter) == rent,
+                    "StorageRent hold does not match the submitter's posts"
+                );
+            }
+
+            let mut appellants: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+            for (_id, post) in Posts::<T>::iter().filter(|(_, post)| !post.is_ended()) {
+                for round in post.rounds {
+                    *appellants.entry(round.appellant).or_insert(Zero::zero()) += round.bond;
+                }
+            }
+
+            for (appellant, bonded) in appellants {
+                ensure!(
+                    <<T as Config>::NativeBalance>::balance_on_hold(&HoldReason::AppealBond.into(), &appellant) == bonded,
+                    "AppealBond hold does not match the appellant's appeals"
+                );
+            }
+
+            let mut reporters: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+            for (_id, reporter, deposit) in Reports::<T>::iter() {
+                *reporters.entry(reporter).or_insert(Zero::zero()) += deposit;
+            }
+
+            for (reporter, deposits) in reporters {
+                ensure!(
+                    <<T as Config>::NativeBalance>::balance_on_hold(&HoldReason::ReportDeposit.into(), &reporter) == deposits,
+                    "ReportDeposit hold does not match the reporter's reports"
+                );
+            }
+
+            Ok(())
+        }
+
+        fn try_state_freezes() -> Result<(), frame_support::sp_runtime::TryRuntimeError> {
+            use frame_support::traits::fungible::{InspectFreeze, InspectHold};
+            use scale_info::prelude::collections::BTreeMap;
+
+            let mut voters: BTreeMap<T::AccountId, (BalanceOf<T>, BalanceOf<T>)> = BTreeMap::new();
+            let mut amounts: BTreeMap<T::AccountId, Vec<BalanceOf<T>>> = BTreeMap::new();
+
+            for (voter, _id, (amount, _direction)) in Votes::<T>::iter() {
+                let (largest, total) = voters.entry(voter.clone()).or_insert((Zero::zero(), Zero::zero()));
+                if amount > *largest {
+                    *largest = amount;
+                }
+                *total += amount;
+                amounts.entry(voter).or_default().push(amount);
+            }
+
+            for (voter, tracked) in VoteAmounts::<T>::iter() {
+                let mut tracked = tracked.into_inner();
+                tracked.sort();
+                let mut votes = amounts.remove(&voter).unwrap_or_default();
+                votes.sort();
+                ensure!(tracked == votes, "VoteAmounts does not match the voter's votes");
+            }
+            ensure!(amounts.is_empty(), "A voter's votes are missing from VoteAmounts");
+
+            // Accounts without votes must have nothing left frozen or held for them
+            let idle: Vec<T::AccountId> = frame_system::Account::<T>::iter_keys().filter(|account| !voters.contains_key(account)).collect();
+            let idle = idle.into_iter().map(|account| (account, (Zero::zero(), Zero::zero())));
+
+            // Parimutuel votes are held as stakes instead of frozen
+            for (voter, (largest, total)) in voters.into_iter().chain(idle) {
+                if T::Parimutuel::get() {
+                    ensure!(
+                        <<T as Config>::NativeBalance>::balance_on_hold(&HoldReason::VoteStake.into(), &voter) == total,
+                        "VoteStake hold does not match the voter's votes"
+                    );
+                } else {
+                    ensure!(
+                        <<T as Config>::NativeBalance>::balance_frozen(&FreezeReason::Vote.into(), &voter) == largest,
+                        "Vote freeze does not match the voter's largest vote"
+                    );
+                }
+            }
 
             Ok(())
         }
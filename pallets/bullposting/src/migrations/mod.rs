@@ -0,0 +1,20 @@
+//! Storage migrations for pallet-bullposting.
+//!
+//! Every migration lives in its own `vN` module and only runs when the on-chain storage version
+//! matches, bumping the version once it is done. The work of every migration so far grows with the
+//! chain's state, so they are exposed as a `SteppedMigration` for `pallet-migrations`.
+
+/// Version 0 to version 1: records the submission block and the verdict in [`crate::Post`].
+pub mod v1;
+
+/// Version 1 to version 2: moves `Voters` from one bounded vector per post to a double map.
+pub mod v2;
+
+/// Version 2 to version 3: adds the history of appealed voting rounds to [`crate::Post`].
+pub mod v3;
+
+/// Version 3 to version 4: counts each submitter's posts into `Submissions` for the rate limits.
+pub mod v4;
+
+/// Version 4 to version 5: collects the amounts of each account's votes into `VoteAmounts`.
+pub mod v5;
@@ -0,0 +1,183 @@
+//! Migrates `Posts` from the version 0 layout to the version 1 layout.
+//!
+//! Version 0 stored `ended: bool` on each post. Version 1 replaces it with `result`, the verdict
+//! the post was ended with, and adds `submitted_at`, the block the post was submitted at.
+//!
+//! Both layouts are declared here, so later changes to [`crate::Post`] do not change what this
+//! migration writes.
+//!
+//! A chain can hold any number of posts, so this is a multi-block migration that translates as many
+//! posts as fit in the weight it is given and carries on in the next block.
+
+use crate::{weights::WeightInfo, BalanceOf, Config, Direction, Pallet};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+    pallet_prelude::*,
+    sp_runtime::Saturating,
+    weights::WeightMeter,
+};
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+
+#[cfg(feature = "try-runtime")]
+use scale_info::prelude::vec::Vec;
+
+use super::v2::PALLET_MIGRATIONS_ID;
+
+/// The version 0 storage layout.
+pub mod v0 {
+    use super::*;
+
+    /// The layout of [`Post`] before version 1.
+    #[derive(MaxEncodedLen, Debug, PartialEq, Clone, Encode, Decode, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct OldPost<T: Config> {
+        pub submitter: T::AccountId,
+        pub bond: BalanceOf<T>,
+        pub bull_votes: BalanceOf<T>,
+        pub bear_votes: BalanceOf<T>,
+        pub voting_until: BlockNumberFor<T>,
+        pub ended: bool,
+    }
+
+    /// `Posts` as it was stored in version 0.
+    #[frame_support::storage_alias]
+    pub type Posts<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, [u8; 32], OldPost<T>>;
+}
+
+/// The layout of a post in version 1.
+#[derive(MaxEncodedLen, Debug, PartialEq, Clone, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct Post<T: Config> {
+    pub submitter: T::AccountId,
+    pub bond: BalanceOf<T>,
+    pub bull_votes: BalanceOf<T>,
+    pub bear_votes: BalanceOf<T>,
+    pub submitted_at: BlockNumberFor<T>,
+    pub voting_until: BlockNumberFor<T>,
+    pub result: Option<Direction>,
+}
+
+impl<T: Config> Post<T> {
+    /// Whether the post has been ended.
+    pub fn is_ended(&self) -> bool {
+        self.result.is_some()
+    }
+
+    /// The direction the tallies point in.
+    pub fn tally(&self) -> Direction {
+        if self.bull_votes > self.bear_votes {
+            Direction::Bullish
+        } else if self.bull_votes < self.bear_votes {
+            Direction::Bearish
+        } else {
+            Direction::Tie
+        }
+    }
+}
+
+/// `Posts` as it was stored in version 1.
+#[frame_support::storage_alias]
+pub type Posts<T: Config> =
+    StorageMap<Pallet<T>, Blake2_128Concat, [u8; 32], Post<T>>;
+
+/// Translates every post to the version 1 layout.
+///
+/// `submitted_at` is recovered from `voting_until`, which version 0 set to the submission block plus
+/// `VotingPeriod`. It is exact as long as `VotingPeriod` has not changed since the posts were
+/// submitted. `result` is recomputed from the final tallies of posts that had already been ended.
+///
+/// The cursor is the last post that was translated. It only runs while the on-chain storage version
+/// is 0 and sets it to 1 once the last post has been translated.
+pub struct LazyMigrationV0ToV1<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> SteppedMigration for LazyMigrationV0ToV1<T> {
+    type Cursor = [u8; 32];
+    type Identifier = MigrationId<18>;
+
+    fn id() -> Self::Identifier {
+        MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 0, version_to: 1 }
+    }
+
+    fn step(
+        mut cursor: Option<Self::Cursor>,
+        meter: &mut WeightMeter,
+    ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+        if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+            return Ok(None);
+        }
+
+        let required = T::WeightInfo::migrate_v0_to_v1_step();
+        if meter.remaining().any_lt(required) {
+            return Err(SteppedMigrationError::InsufficientWeight { required });
+        }
+
+        // Posts after the cursor are still in the old layout, as translating keeps their keys
+        loop {
+            if meter.try_consume(required).is_err() {
+                return Ok(cursor);
+            }
+
+            let mut iter = match cursor {
+                Some(id) => v0::Posts::<T>::iter_from(v0::Posts::<T>::hashed_key_for(id)),
+                None => v0::Posts::<T>::iter(),
+            };
+
+            let Some((id, old)) = iter.next() else {
+                StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+                return Ok(None);
+            };
+
+            let mut post = Post {
+                submitter: old.submitter,
+                bond: old.bond,
+                bull_votes: old.bull_votes,
+                bear_votes: old.bear_votes,
+                submitted_at: old.voting_until.saturating_sub(T::VotingPeriod::get()),
+                voting_until: old.voting_until,
+                result: None,
+            };
+            if old.ended {
+                post.result = Some(post.tally());
+            }
+
+            Posts::<T>::insert(id, post);
+            cursor = Some(id);
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let posts: Vec<([u8; 32], bool)> = v0::Posts::<T>::iter()
+            .map(|(id, post)| (id, post.ended))
+            .collect();
+
+        Ok(posts.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let posts = <Vec<([u8; 32], bool)>>::decode(&mut &state[..])
+            .map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+        ensure!(
+            Posts::<T>::iter().count() == posts.len(),
+            "The number of posts changed during the migration"
+        );
+
+        for (id, ended) in posts {
+            let post = Posts::<T>::get(id).ok_or("A post was lost during the migration")?;
+            ensure!(post.is_ended() == ended, "A post's ended state changed during the migration");
+            ensure!(post.submitted_at <= post.voting_until, "A post was submitted after its voting ended");
+            if let Some(result) = &post.result {
+                ensure!(*result == post.tally(), "A post's result does not match its tallies");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+//! Migrates `Voters` from the version 1 layout to the version 2 layout.
+//!
+//! Version 1 stored every voter on a post in a single `BoundedVec` capped at `MaxVoters`. Version 2
+//! stores one `(post, voter)` entry per vote in a double map, which removes the cap and lets
+//! `resolve_post` drain a post's voters by prefix.
+//!
+//! A chain can hold any number of posts, so this is a multi-block migration that moves as many
+//! posts as fit in the weight it is given and carries on in the next block.
+
+use crate::{weights::WeightInfo, Config, Pallet, Voters};
+use codec::Decode;
+use frame_support::{
+    migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+    pallet_prelude::*,
+    storage::StoragePrefixedMap,
+    weights::WeightMeter,
+};
+use scale_info::prelude::vec::Vec;
+
+#[cfg(feature = "try-runtime")]
+use crate::VoteCounts;
+#[cfg(feature = "try-runtime")]
+use codec::Encode;
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+
+/// The identifier prefix shared by the pallet's multi-block migrations.
+pub const PALLET_MIGRATIONS_ID: &[u8; 18] = b"pallet-bullposting";
+
+/// The version 1 storage layout.
+pub mod v1 {
+    use super::*;
+
+    /// `Voters` as it was stored in version 1.
+    ///
+    /// The old `BoundedVec` shares its encoding with `Vec`, so the `MaxVoters` bound is not needed
+    /// to decode it.
+    #[frame_support::storage_alias]
+    pub type Voters<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        [u8; 32],
+        Vec<<T as frame_system::Config>::AccountId>,
+    >;
+}
+
+/// Moves every post's voter list into the `Voters` double map, one whole post at a time.
+///
+/// The cursor is the last post that was moved. It only runs while the on-chain storage version is 1
+/// and sets it to 2 once the last post has been moved.
+pub struct LazyMigrationV1ToV2<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> LazyMigrationV1ToV2<T> {
+    /// The next post still stored in the version 1 layout, after `cursor`.
+    ///
+    /// Both layouts live under the same storage prefix, and a post's new entries are keyed by its
+    /// old key followed by the voter. The search therefore starts past the largest key the cursor's
+    /// new entries can have, so they are skipped in a single read.
+    fn next_post(cursor: Option<[u8; 32]>) -> Result<Option<[u8; 32]>, SteppedMigrationError> {
+        let prefix = v1::Voters::<T>::final_prefix();
+        let start = match cursor {
+            Some(id) => {
+                let voter_len = 16 + T::AccountId::max_encoded_len();
+                let mut key = v1::Voters::<T>::hashed_key_for(id);
+                key.resize(key.len() + voter_len, 0xff);
+                key
+            },
+            None => prefix.to_vec(),
+        };
+
+        let Some(key) = sp_io::storage::next_key(&start).filter(|key| key.starts_with(&prefix)) else {
+            return Ok(None);
+        };
+
+        // An old key is the prefix, the 16 byte hash and the post id
+        ensure!(key.len() == prefix.len() + 16 + 32, SteppedMigrationError::Failed);
+        <[u8; 32]>::decode(&mut &key[prefix.len() + 16..])
+            .map(Some)
+            .map_err(|_| SteppedMigrationError::Failed)
+    }
+}
+
+impl<T: Config> SteppedMigration for LazyMigrationV1ToV2<T> {
+    type Cursor = [u8; 32];
+    type Identifier = MigrationId<18>;
+
+    fn id() -> Self::Identifier {
+        MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 1, version_to: 2 }
+    }
+
+    fn step(
+        mut cursor: Option<Self::Cursor>,
+        meter: &mut WeightMeter,
+    ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+        if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+            return Ok(None);
+        }
+
+        let mut moved = false;
+        loop {
+            // Finding the next post and reading its length is needed before its full cost is known
+            let lookup = T::WeightInfo::migrate_v1_to_v2_step(0);
+            if !meter.can_consume(lookup) {
+                return match moved {
+                    true => Ok(cursor),
+                    false => Err(SteppedMigrationError::InsufficientWeight { required: lookup }),
+                };
+            }
+
+            let Some(id) = Self::next_post(cursor)? else {
+                meter.consume(lookup);
+                StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+                return Ok(None);
+            };
+
+            let voters = v1::Voters::<T>::decode_len(id).unwrap_or_default() as u32;
+            let required = T::WeightInfo::migrate_v1_to_v2_step(voters);
+            if meter.try_consume(required).is_err() {
+                return match moved {
+                    true => Ok(cursor),
+                    false => Err(SteppedMigrationError::InsufficientWeight { required }),
+                };
+            }
+
+            for account in v1::Voters::<T>::take(id).unwrap_or_default() {
+                Voters::<T>::insert(id, account, ());
+            }
+            cursor = Some(id);
+            moved = true;
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let voters: Vec<([u8; 32], u32)> = v1::Voters::<T>::iter()
+            .map(|(id, accounts)| (id, accounts.len() as u32))
+            .collect();
+
+        Ok(voters.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let voters = <Vec<([u8; 32], u32)>>::decode(&mut &state[..])
+            .map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+        let total: u32 = voters.iter().map(|(_, count)| count).sum();
+        ensure!(
+            Voters::<T>::iter_keys().count() as u32 == total,
+            "The number of voters changed during the migration"
+        );
+
+        for (id, count) in voters {
+            ensure!(
+                Voters::<T>::iter_key_prefix(id).count() as u32 == count,
+                "A post's voters changed during the migration"
+            );
+            ensure!(
+                VoteCounts::<T>::get(id).unwrap_or_default() == count,
+                "A post's vote count does not match its voters"
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,131 @@
+//! Migrates `Posts` from the version 2 layout to the version 3 layout.
+//!
+//! Version 3 adds `rounds` to each post, the history of its appealed voting rounds. Posts submitted
+//! before appeals existed have never been appealed, so they all start with an empty history.
+//!
+//! A chain can hold any number of posts, so this is a multi-block migration that translates as many
+//! posts as fit in the weight it is given and carries on in the next block.
+
+use crate::{weights::WeightInfo, Config, Pallet, Post, Posts};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+    migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+    pallet_prelude::*,
+    weights::WeightMeter,
+};
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+use frame_system::pallet_prelude::BlockNumberFor;
+use scale_info::TypeInfo;
+
+#[cfg(feature = "try-runtime")]
+use scale_info::prelude::vec::Vec;
+
+use super::v2::PALLET_MIGRATIONS_ID;
+
+/// The version 2 storage layout, which versions 1 and 2 share.
+pub mod v2 {
+    use super::*;
+    use crate::{BalanceOf, Direction};
+
+    /// The layout of [`Post`] before version 3.
+    #[derive(MaxEncodedLen, Debug, PartialEq, Clone, Encode, Decode, TypeInfo)]
+    #[scale_info(skip_type_params(T))]
+    pub struct OldPost<T: Config> {
+        pub submitter: T::AccountId,
+        pub bond: BalanceOf<T>,
+        pub bull_votes: BalanceOf<T>,
+        pub bear_votes: BalanceOf<T>,
+        pub submitted_at: BlockNumberFor<T>,
+        pub voting_until: BlockNumberFor<T>,
+        pub result: Option<Direction>,
+    }
+
+    /// `Posts` as it was stored in version 2.
+    #[frame_support::storage_alias]
+    pub type Posts<T: Config> =
+        StorageMap<Pallet<T>, Blake2_128Concat, [u8; 32], OldPost<T>>;
+}
+
+/// Translates every post to the version 3 layout, with no appealed rounds.
+///
+/// The cursor is the last post that was translated. It only runs while the on-chain storage version
+/// is 2 and sets it to 3 once the last post has been translated.
+pub struct LazyMigrationV2ToV3<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> SteppedMigration for LazyMigrationV2ToV3<T> {
+    type Cursor = [u8; 32];
+    type Identifier = MigrationId<18>;
+
+    fn id() -> Self::Identifier {
+        MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 2, version_to: 3 }
+    }
+
+    fn step(
+        mut cursor: Option<Self::Cursor>,
+        meter: &mut WeightMeter,
+    ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+        if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+            return Ok(None);
+        }
+
+        let required = T::WeightInfo::migrate_v2_to_v3_step();
+        if meter.remaining().any_lt(required) {
+            return Err(SteppedMigrationError::InsufficientWeight { required });
+        }
+
+        // Posts after the cursor are still in the old layout, as translating keeps their keys
+        loop {
+            if meter.try_consume(required).is_err() {
+                return Ok(cursor);
+            }
+
+            let mut iter = match cursor {
+                Some(id) => v2::Posts::<T>::iter_from(v2::Posts::<T>::hashed_key_for(id)),
+                None => v2::Posts::<T>::iter(),
+            };
+
+            let Some((id, old)) = iter.next() else {
+                StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+                return Ok(None);
+            };
+
+            Posts::<T>::insert(id, Post {
+                submitter: old.submitter,
+                bond: old.bond,
+                bull_votes: old.bull_votes,
+                bear_votes: old.bear_votes,
+                submitted_at: old.submitted_at,
+                voting_until: old.voting_until,
+                result: old.result,
+                rounds: BoundedVec::new(),
+            });
+            cursor = Some(id);
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        let posts: Vec<[u8; 32]> = v2::Posts::<T>::iter_keys().collect();
+
+        Ok(posts.encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let posts = <Vec<[u8; 32]>>::decode(&mut &state[..])
+            .map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+        ensure!(
+            Posts::<T>::iter().count() == posts.len(),
+            "The number of posts changed during the migration"
+        );
+
+        for id in posts {
+            let post = Posts::<T>::get(id).ok_or("A post was lost during the migration")?;
+            ensure!(post.rounds.is_empty(), "A migrated post has appealed rounds");
+        }
+
+        Ok(())
+    }
+}
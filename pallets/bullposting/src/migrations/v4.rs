@@ -0,0 +1,94 @@
+//! Populates `Submissions` for the posts stored before version 4.
+//!
+//! Version 4 limits the number of unresolved posts per submitter and the rate at which they submit,
+//! tracked in `Submissions`. Every post still in storage counts towards its submitter's active
+//! posts, and their latest `submitted_at` becomes the start of their cooldown.
+//!
+//! A chain can hold any number of posts, so this is a multi-block migration that counts as many
+//! posts as fit in the weight it is given and carries on in the next block.
+
+use crate::{weights::WeightInfo, Config, Pallet, Posts, Submissions};
+use frame_support::{
+    migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+    pallet_prelude::*,
+    weights::WeightMeter,
+};
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use scale_info::prelude::vec::Vec;
+
+use super::v2::PALLET_MIGRATIONS_ID;
+
+/// Counts the posts of every submitter into `Submissions`.
+///
+/// The cursor is the last post that was counted. It only runs while the on-chain storage version is
+/// 3 and sets it to 4 once the last post has been counted.
+pub struct LazyMigrationV3ToV4<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> SteppedMigration for LazyMigrationV3ToV4<T> {
+    type Cursor = [u8; 32];
+    type Identifier = MigrationId<18>;
+
+    fn id() -> Self::Identifier {
+        MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 3, version_to: 4 }
+    }
+
+    fn step(
+        mut cursor: Option<Self::Cursor>,
+        meter: &mut WeightMeter,
+    ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+        if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+            return Ok(None);
+        }
+
+        let required = T::WeightInfo::migrate_v3_to_v4_step();
+        if meter.remaining().any_lt(required) {
+            return Err(SteppedMigrationError::InsufficientWeight { required });
+        }
+
+        loop {
+            if meter.try_consume(required).is_err() {
+                return Ok(cursor);
+            }
+
+            let mut iter = match cursor {
+                Some(id) => Posts::<T>::iter_from(Posts::<T>::hashed_key_for(id)),
+                None => Posts::<T>::iter(),
+            };
+
+            let Some((id, post)) = iter.next() else {
+                StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+                return Ok(None);
+            };
+
+            Submissions::<T>::mutate(post.submitter, |submission| {
+                let (active_posts, last_submitted_at) = submission.get_or_insert((0, post.submitted_at));
+                *active_posts += 1;
+                *last_submitted_at = (*last_submitted_at).max(post.submitted_at);
+            });
+            cursor = Some(id);
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        ensure!(Submissions::<T>::iter().next().is_none(), "Submissions is not empty before the migration");
+
+        Ok((Posts::<T>::iter().count() as u32).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let posts = u32::decode(&mut &state[..])
+            .map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+        let active_posts: u32 = Submissions::<T>::iter_values().map(|(active_posts, _)| active_posts).sum();
+        ensure!(active_posts == posts, "Active posts do not add up to the number of posts");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,110 @@
+//! Populates `VoteAmounts` for the votes stored before version 5.
+//!
+//! Version 5 keeps the amounts of each account's votes that are not yet unfrozen in `VoteAmounts`,
+//! so the account's shared freeze can be kept at its largest vote without reading all of its
+//! `Votes`. Every vote still in storage is added to its voter's entry.
+//!
+//! `VoteAmounts` holds up to `MaxVotesPerAccount` amounts, which versions before 5 did not limit.
+//! An account with more votes keeps its largest amounts, so its freeze stays at its largest vote
+//! until those votes are unfrozen. `pre_upgrade` checks that no account has more votes than that.
+//!
+//! A chain can hold any number of votes, so this is a multi-block migration that adds as many
+//! votes as fit in the weight it is given and carries on in the next block.
+
+use crate::{weights::WeightInfo, Config, Pallet, VoteAmounts, Votes};
+use frame_support::{
+    migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+    pallet_prelude::*,
+    weights::WeightMeter,
+};
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+
+#[cfg(feature = "try-runtime")]
+use codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use scale_info::prelude::{collections::BTreeMap, vec::Vec};
+
+use super::v2::PALLET_MIGRATIONS_ID;
+
+/// Adds the amount of every vote to its voter's `VoteAmounts`.
+///
+/// The cursor is the voter and post of the last vote that was added. It only runs while the
+/// on-chain storage version is 4 and sets it to 5 once the last vote has been added.
+pub struct LazyMigrationV4ToV5<T>(core::marker::PhantomData<T>);
+
+impl<T: Config> SteppedMigration for LazyMigrationV4ToV5<T> {
+    type Cursor = (T::AccountId, [u8; 32]);
+    type Identifier = MigrationId<18>;
+
+    fn id() -> Self::Identifier {
+        MigrationId { pallet_id: *PALLET_MIGRATIONS_ID, version_from: 4, version_to: 5 }
+    }
+
+    fn step(
+        mut cursor: Option<Self::Cursor>,
+        meter: &mut WeightMeter,
+    ) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+        if Pallet::<T>::on_chain_storage_version() != Self::id().version_from as u16 {
+            return Ok(None);
+        }
+
+        let required = T::WeightInfo::migrate_v4_to_v5_step();
+        if meter.remaining().any_lt(required) {
+            return Err(SteppedMigrationError::InsufficientWeight { required });
+        }
+
+        loop {
+            if meter.try_consume(required).is_err() {
+                return Ok(cursor);
+            }
+
+            let mut iter = match cursor {
+                Some((voter, id)) => Votes::<T>::iter_from(Votes::<T>::hashed_key_for(voter, id)),
+                None => Votes::<T>::iter(),
+            };
+
+            let Some((voter, id, (amount, _direction))) = iter.next() else {
+                StorageVersion::new(Self::id().version_to as u16).put::<Pallet<T>>();
+                return Ok(None);
+            };
+
+            // Past the limit, the smallest amount makes way for a larger one
+            VoteAmounts::<T>::mutate(&voter, |amounts| {
+                if amounts.try_push(amount).is_err() {
+                    if let Some(smallest) = amounts.iter_mut().min() {
+                        *smallest = (*smallest).max(amount);
+                    }
+                }
+            });
+            cursor = Some((voter, id));
+        }
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+        ensure!(VoteAmounts::<T>::iter().next().is_none(), "VoteAmounts is not empty before the migration");
+
+        let mut voters: BTreeMap<T::AccountId, u32> = BTreeMap::new();
+        for voter in Votes::<T>::iter_keys().map(|(voter, _id)| voter) {
+            *voters.entry(voter).or_insert(0) += 1;
+        }
+        ensure!(
+            voters.values().all(|votes| *votes <= T::MaxVotesPerAccount::get()),
+            "An account has more votes than MaxVotesPerAccount"
+        );
+
+        Ok((voters.values().sum::<u32>()).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+        let votes = u32::decode(&mut &state[..])
+            .map_err(|_| "Failed to decode the pre-upgrade state")?;
+
+        let tracked: u32 = VoteAmounts::<T>::iter_values().map(|amounts| amounts.len() as u32).sum();
+        ensure!(tracked == votes, "VoteAmounts do not add up to the number of votes");
+
+        Ok(())
+    }
+}
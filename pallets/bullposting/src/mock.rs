@@ -2,11 +2,16 @@ use crate as pallet_bullposting;
 use frame_support::{
     derive_impl,
     parameter_types,
+    traits::SortedMembers,
+};
+use frame_support::sp_runtime::{
+    testing::{TestSignature, TestXt, UintAuthorityId},
+    BuildStorage,
+    Percent,
 };
-use sp_runtime::BuildStorage;
 
 type Block = frame_system::mocking::MockBlock<Test>;
-type Balance = u64;
+pub type Balance = u64;
 
 #[frame_support::runtime]
 mod runtime {
@@ -53,6 +58,39 @@ impl pallet_balances::Config for Test {
     type MaxFreezes = MaxFreezes;
 }
 
+// The offchain worker's unsigned transactions, as decoded from the test transaction pool
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type Extrinsic = Extrinsic;
+    type RuntimeCall = RuntimeCall;
+}
+
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_inherent(call: Self::RuntimeCall) -> Self::Extrinsic {
+        Extrinsic::new_bare(call)
+    }
+}
+
+// Attestations are signed with test keys whose account is their number
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+pub struct TestAuthId;
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+}
+
 type BlockNumber = u64;
 
 parameter_types! {
@@ -67,10 +105,53 @@ parameter_types! {
     pub const VotingPeriod: BlockNumber = 1000;
     pub const BondMinimum: u32 = 50;
     pub const VoteMinimum: u32 = 50;
-    pub const MaxVoters: u32 = 2000;
     pub const StorageRent: u32 = 100;
     pub const MaxUrlLength: u32 = 2000;
     pub const UnfreezeLimit: u32 = 1000;
+    pub const ReportDeposit: u32 = 20;
+    pub const AppealPeriod: BlockNumber = 100;
+    pub const AppealVotingPeriod: BlockNumber = 2000;
+    pub const AppealVoteMinimum: u32 = 100;
+    pub const AppealBondCoefficient: u32 = 200;
+    pub const MaxAppeals: u32 = 1;
+    pub const MaxBatchSize: u32 = 5;
+    pub const MaxActivePosts: u32 = 3;
+    // Tests lower it to reach the limit
+    pub static MaxVotesPerAccount: u32 = 10;
+    // No cooldown unless a test sets one, so tests can submit several posts in one block
+    pub static CooldownPeriod: BlockNumber = 0;
+    pub static SelfVotePolicy: pallet_bullposting::SelfVotePolicy = pallet_bullposting::SelfVotePolicy::Allow;
+    // No keeper rewards unless a test sets them, so balances only change by rewards and slashes
+    pub static KeeperReward: u32 = 0;
+    pub const UnsignedPriority: u64 = 100;
+    pub const UnsignedLongevity: BlockNumber = 5;
+    // URLs are not fetched unless a test enables it, as the test HTTP client panics on unexpected requests
+    pub static AttestUrls: bool = false;
+    pub const MaxTitleLength: u32 = 16;
+    pub const MaxAttesters: u32 = 4;
+    // Outcomes are kept forever unless a test sets a retention
+    pub static OutcomeRetention: BlockNumber = 0;
+    // Votes are frozen unless a test stakes them
+    pub static Parimutuel: bool = false;
+    pub const ParimutuelFee: Percent = Percent::from_percent(10);
+    // Votes count at their size unless a test weights them
+    pub static AccuracyWeight: Percent = Percent::zero();
+    // Anyone can submit posts and vote unless a test restricts it to these accounts
+    pub static Members: Option<Vec<u64>> = None;
+}
+
+// The members of the board for the `SubmitOrigin` and `VoteOrigin`, every account while `Members` is not set
+pub struct BoardMembers;
+impl SortedMembers<u64> for BoardMembers {
+    fn sorted_members() -> Vec<u64> {
+        let mut members = Members::get().unwrap_or_default();
+        members.sort();
+        members
+    }
+
+    fn contains(who: &u64) -> bool {
+        Members::get().is_none_or(|members| members.contains(who))
+    }
 }
 
 impl pallet_bullposting::Config for Test {
@@ -89,18 +170,55 @@ impl pallet_bullposting::Config for Test {
     type VotingPeriod = VotingPeriod;
     type BondMinimum = BondMinimum;
     type VoteMinimum = VoteMinimum;
-    type MaxVoters = MaxVoters;
     type StorageRent = StorageRent;
     type MaxUrlLength = MaxUrlLength;
     type UnfreezeLimit = UnfreezeLimit;
+    type ModeratorOrigin = frame_system::EnsureRoot<u64>;
+    type SubmitOrigin = frame_system::EnsureSignedBy<BoardMembers, u64>;
+    type VoteOrigin = frame_system::EnsureSignedBy<BoardMembers, u64>;
+    type PauseOrigin = frame_system::EnsureRoot<u64>;
+    type ReportDeposit = ReportDeposit;
+    type AppealPeriod = AppealPeriod;
+    type AppealVotingPeriod = AppealVotingPeriod;
+    type AppealVoteMinimum = AppealVoteMinimum;
+    type AppealBondCoefficient = AppealBondCoefficient;
+    type MaxAppeals = MaxAppeals;
+    type MaxBatchSize = MaxBatchSize;
+    type MaxActivePosts = MaxActivePosts;
+    type MaxVotesPerAccount = MaxVotesPerAccount;
+    type CooldownPeriod = CooldownPeriod;
+    type SelfVotePolicy = SelfVotePolicy;
+    type KeeperReward = KeeperReward;
+    type UnsignedPriority = UnsignedPriority;
+    type UnsignedLongevity = UnsignedLongevity;
+    type AttestUrls = AttestUrls;
+    type MaxTitleLength = MaxTitleLength;
+    type AuthorityId = TestAuthId;
+    type MaxAttesters = MaxAttesters;
+    type OutcomeRetention = OutcomeRetention;
+    type Parimutuel = Parimutuel;
+    type ParimutuelFee = ParimutuelFee;
+    type AccuracyWeight = AccuracyWeight;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
+    new_test_ext_with_balances(vec![(0, 1001), (1, 1001), (2, 1001), (3, 1001), (10000, 1001)])
+}
+
+// Build genesis storage with the given accounts funded.
+pub fn new_test_ext_with_balances(balances: Vec<(u64, Balance)>) -> sp_io::TestExternalities {
+    new_test_ext_with_genesis(balances, Default::default())
+}
+
+// Build genesis storage with the given accounts funded and the given posts and votes.
+pub fn new_test_ext_with_genesis(
+    balances: Vec<(u64, Balance)>,
+    bullposting: pallet_bullposting::GenesisConfig<Test>,
+) -> sp_io::TestExternalities {
     let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
-    let genesis = pallet_balances::GenesisConfig::<Test> { 
-        balances: vec![(0, 1001), (1, 1001), (2, 1001), (3, 1001), (10000, 1001)]
-    };
+    let genesis = pallet_balances::GenesisConfig::<Test> { balances };
     genesis.assimilate_storage(&mut t).unwrap();
+    bullposting.assimilate_storage(&mut t).unwrap();
     t.into()
 }
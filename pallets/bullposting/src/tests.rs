@@ -1,7 +1,33 @@
-use crate::{mock::*, Error, Event};
-use frame_support::{assert_noop, assert_ok};
-use frame_support::traits::fungible::{Inspect, InspectHold, Mutate, InspectFreeze};
+use crate::{harness::{self, Action}, mock::*, Error, Event};
+use crate::migrations::v1::{v0, LazyMigrationV0ToV1};
+use crate::migrations::v2::{v1, LazyMigrationV1ToV2};
+use crate::migrations::v3::{v2, LazyMigrationV2ToV3};
+use crate::migrations::v4::LazyMigrationV3ToV4;
+use crate::migrations::v5::LazyMigrationV4ToV5;
+use frame_support::{assert_noop, BoundedVec};
+use frame_support::dispatch::{GetDispatchInfo, Pays};
+use frame_support::traits::{GetStorageVersion, StorageVersion};
+use frame_support::traits::fungible::{Inspect, InspectFreeze, InspectHold, Mutate};
 use frame_support::traits::tokens::{Preservation, Fortitude};
+use frame_support::traits::Hooks;
+use frame_support::migrations::{SteppedMigration, SteppedMigrationError};
+use frame_support::weights::{Weight, WeightMeter};
+use crate::weights::WeightInfo;
+use frame_support::unsigned::{TransactionSource, ValidateUnsigned};
+use frame_support::pallet_prelude::InvalidTransaction;
+use codec::{Decode, Encode};
+use sp_core::offchain::{testing, OffchainDbExt, OffchainWorkerExt, TransactionPoolExt};
+use sp_runtime::testing::{TestSignature, UintAuthorityId};
+use proptest::prelude::*;
+
+
+// Checks the pallet's invariants after every successful call, on top of `frame_support::assert_ok`
+macro_rules! assert_ok {
+    ($call:expr) => {{
+        frame_support::assert_ok!($call);
+        frame_support::assert_ok!(Bullposting::do_try_state());
+    }};
+}
 
 
 #[test]
@@ -44,8 +70,10 @@ fn test_try_submit_post() {
             bond,
             bull_votes: 0,
             bear_votes: 0,
+            submitted_at: System::block_number(),
             voting_until: System::block_number() + voting_period,
-            ended: false,
+            result: None,
+            rounds: Default::default(),
         };
         assert_eq!(crate::Posts::<Test>::get(post_id), Some(testpost));
         System::assert_last_event(
@@ -148,8 +176,11 @@ fn test_try_submit_vote() {
             let _ = Bullposting::try_submit_vote(RuntimeOrigin::signed(i), post_url.clone(), vote_amount, crate::Direction::Bullish);
         }
 
-        // Can't vote if the maximum number of voters have already voted on this post
-        assert_noop!(Bullposting::try_submit_vote(RuntimeOrigin::signed(david), post_url.clone(), vote_amount, crate::Direction::Bearish), Error::<Test>::VotersMaxed);
+        // There is no cap on the number of voters
+        assert_eq!(crate::VoteCounts::<Test>::get(post_id), Some(2000));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(david), post_url.clone(), vote_amount, crate::Direction::Bearish));
+        assert_eq!(crate::VoteCounts::<Test>::get(post_id), Some(2001));
+        assert!(crate::Voters::<Test>::contains_key(post_id, david));
 
         // Can't vote if the voting period has ended
         System::set_block_number(voting_period + 1);
@@ -261,6 +292,7 @@ fn test_try_end_post() {
         let bond = 300;
         let vote_amount = 500;
         let voting_period = 1000;
+        let appeal_period = 100;
         let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
         let post_id = sp_io::hashing::blake2_256(&post_url);
         let post_2_url: Vec<u8> = "testingtestingblahblah".into();
@@ -284,8 +316,12 @@ fn test_try_end_post() {
         // Cannot end during the voting period
         assert_noop!(Bullposting::try_end_post(RuntimeOrigin::signed(alice), post_url.clone()), Error::<Test>::VotingStillOngoing);
 
-        // End voting period
+        // Cannot end while the verdict can still be appealed
         System::set_block_number(voting_period + 1);
+        assert_noop!(Bullposting::try_end_post(RuntimeOrigin::signed(alice), post_url.clone()), Error::<Test>::AppealWindowOpen);
+
+        // End voting and appeal period
+        System::set_block_number(voting_period + appeal_period + 1);
 
         // Error if submit an empty input for the post
         assert_noop!(Bullposting::try_end_post(RuntimeOrigin::signed(alice), empty_post), Error::<Test>::Empty);
@@ -358,6 +394,7 @@ fn try_resolve_voting() {
         let bond = 300;
         let vote_amount = 500;
         let voting_period = 1000;
+        let appeal_period = 100;
         let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
         let post_id = sp_io::hashing::blake2_256(&post_url);
         let empty_post: Vec<u8> = "".into();
@@ -377,7 +414,7 @@ fn try_resolve_voting() {
         assert_noop!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url.clone()), Error::<Test>::PostUnended);
 
         // End voting
-        System::set_block_number(voting_period + 1);
+        System::set_block_number(voting_period + appeal_period + 1);
         assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
 
         // Error on empty post input
@@ -399,7 +436,7 @@ fn try_resolve_voting() {
         );
 
         // Check everything was removed from storage
-        assert!(!crate::Voters::<Test>::contains_key(post_id));
+        assert!(crate::Voters::<Test>::iter_key_prefix(post_id).next().is_none());
         assert!(!crate::Votes::<Test>::contains_key(charlie, post_id));
         assert!(!crate::Posts::<Test>::contains_key(post_id));
         assert!(!crate::VoteCounts::<Test>::contains_key(post_id));
@@ -415,6 +452,7 @@ fn test_try_resolve_voting_big() {
         let bond = 300;
         let vote_amount = 500;
         let voting_period = 1000;
+        let appeal_period = 100;
         let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
         let post_id = sp_io::hashing::blake2_256(&post_url);
         let empty_post: Vec<u8> = "".into();
@@ -438,7 +476,7 @@ fn test_try_resolve_voting_big() {
         assert_noop!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url.clone()), Error::<Test>::PostUnended);
 
         // End voting
-        System::set_block_number(voting_period + 1);
+        System::set_block_number(voting_period + appeal_period + 1);
         assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
 
         // Error on empty post input
@@ -469,9 +507,1898 @@ fn test_try_resolve_voting_big() {
         );
 
         // Check everything was removed from storage
-        assert!(!crate::Voters::<Test>::contains_key(post_id));
+        assert!(crate::Voters::<Test>::iter_key_prefix(post_id).next().is_none());
         assert!(!crate::Votes::<Test>::contains_key(charlie, post_id));
         assert!(!crate::Posts::<Test>::contains_key(post_id));
         assert!(!crate::VoteCounts::<Test>::contains_key(post_id));
     });
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_migrate_v0_to_v1() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let voting_period = 1000;
+        let open_id = sp_io::hashing::blake2_256(b"still voting");
+        let ended_id = sp_io::hashing::blake2_256(b"already ended");
+
+        // Pretend the chain is still on the version 0 layout
+        StorageVersion::new(0).put::<Bullposting>();
+        v0::Posts::<Test>::insert(open_id, v0::OldPost {
+            submitter: alice,
+            bond: 300,
+            bull_votes: 500,
+            bear_votes: 0,
+            voting_until: 1500,
+            ended: false,
+        });
+        v0::Posts::<Test>::insert(ended_id, v0::OldPost {
+            submitter: bob,
+            bond: 300,
+            bull_votes: 100,
+            bear_votes: 500,
+            voting_until: 1200,
+            ended: true,
+        });
+
+        // A step that can't afford a single post fails without making progress
+        let mut meter = WeightMeter::with_limit(Weight::zero());
+        assert!(matches!(
+            LazyMigrationV0ToV1::<Test>::step(None, &mut meter),
+            Err(SteppedMigrationError::InsufficientWeight { .. })
+        ));
+
+        // With enough weight for one post, each step translates one post
+        let limit = <() as WeightInfo>::migrate_v0_to_v1_step();
+        let mut cursor = None;
+        let mut steps = 0;
+        loop {
+            let mut meter = WeightMeter::with_limit(limit);
+            cursor = LazyMigrationV0ToV1::<Test>::step(cursor, &mut meter).unwrap();
+            steps += 1;
+            if cursor.is_none() {
+                break;
+            }
+            assert_eq!(Bullposting::on_chain_storage_version(), StorageVersion::new(0));
+        }
+        assert_eq!(steps, 3);
+
+        assert_eq!(Bullposting::on_chain_storage_version(), StorageVersion::new(1));
+        assert_eq!(crate::migrations::v1::Posts::<Test>::get(open_id), Some(crate::migrations::v1::Post {
+            submitter: alice,
+            bond: 300,
+            bull_votes: 500,
+            bear_votes: 0,
+            submitted_at: 1500 - voting_period,
+            voting_until: 1500,
+            result: None,
+        }));
+        assert_eq!(crate::migrations::v1::Posts::<Test>::get(ended_id), Some(crate::migrations::v1::Post {
+            submitter: bob,
+            bond: 300,
+            bull_votes: 100,
+            bear_votes: 500,
+            submitted_at: 1200 - voting_period,
+            voting_until: 1200,
+            result: Some(crate::Direction::Bearish),
+        }));
+
+        // Running it again is a no-op as the storage version has already been bumped
+        crate::migrations::v1::Posts::<Test>::remove(open_id);
+        let mut meter = WeightMeter::new();
+        assert_eq!(LazyMigrationV0ToV1::<Test>::step(None, &mut meter).unwrap(), None);
+        assert!(!crate::migrations::v1::Posts::<Test>::contains_key(open_id));
+        assert!(crate::migrations::v1::Posts::<Test>::contains_key(ended_id));
+    });
+}
+
+#[test]
+fn test_migrate_v1_to_v2() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let post_id = sp_io::hashing::blake2_256(b"one post");
+        let post_2_id = sp_io::hashing::blake2_256(b"another post");
+
+        // Pretend the chain is still on the version 1 layout
+        StorageVersion::new(1).put::<Bullposting>();
+        v1::Voters::<Test>::insert(post_id, vec![alice, bob, charlie]);
+        v1::Voters::<Test>::insert(post_2_id, vec![bob]);
+
+        // A step that can't afford a single post fails without making progress
+        let mut meter = WeightMeter::with_limit(Weight::zero());
+        assert!(matches!(
+            LazyMigrationV1ToV2::<Test>::step(None, &mut meter),
+            Err(SteppedMigrationError::InsufficientWeight { .. })
+        ));
+
+        // With enough weight for the larger post, each step moves one post
+        let limit = <() as WeightInfo>::migrate_v1_to_v2_step(3);
+        let mut cursor = None;
+        let mut steps = 0;
+        loop {
+            let mut meter = WeightMeter::with_limit(limit);
+            cursor = LazyMigrationV1ToV2::<Test>::step(cursor, &mut meter).unwrap();
+            steps += 1;
+            if cursor.is_none() {
+                break;
+            }
+            assert_eq!(Bullposting::on_chain_storage_version(), StorageVersion::new(1));
+        }
+        assert_eq!(steps, 3);
+
+        assert_eq!(Bullposting::on_chain_storage_version(), StorageVersion::new(2));
+        let mut voters: Vec<u64> = crate::Voters::<Test>::iter_key_prefix(post_id).collect();
+        voters.sort();
+        assert_eq!(voters, vec![alice, bob, charlie]);
+        assert_eq!(crate::Voters::<Test>::iter_key_prefix(post_2_id).collect::<Vec<u64>>(), vec![bob]);
+        assert!(!v1::Voters::<Test>::contains_key(post_id));
+        assert!(!v1::Voters::<Test>::contains_key(post_2_id));
+
+        // Running it again is a no-op as the storage version has already been bumped
+        let mut meter = WeightMeter::new();
+        assert_eq!(LazyMigrationV1ToV2::<Test>::step(None, &mut meter).unwrap(), None);
+        assert_eq!(crate::Voters::<Test>::iter_keys().count(), 4);
+    });
+}
+
+#[test]
+fn test_vote_freeze_follows_largest_vote() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let bond = 300;
+        let voting_period = 1000;
+        let appeal_period = 100;
+        let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+        let post_2_url: Vec<u8> = "testingtestingblahblah".into();
+        let post_3_url: Vec<u8> = "https://example.com/third".into();
+        let freeze_of = |who| Balances::balance_frozen(&crate::FreezeReason::Vote.into(), &who);
+        let amounts_of = |who| crate::VoteAmounts::<Test>::get(who).into_inner();
+
+        MaxVotesPerAccount::set(2);
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        System::set_block_number(2);
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_2_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(charlie), post_3_url.clone(), bond));
+
+        // Votes on different posts share the same frozen tokens
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 500, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_2_url.clone(), 300, crate::Direction::Bullish));
+        assert_eq!(freeze_of(bob), 500);
+        assert_eq!(amounts_of(bob), vec![500, 300]);
+
+        // Accounts can only have `MaxVotesPerAccount` votes that are not yet unfrozen
+        assert_noop!(
+            Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_3_url.clone(), 100, crate::Direction::Bullish),
+            Error::<Test>::TooManyVotes
+        );
+
+        // Lowering the largest vote lowers the freeze
+        assert_ok!(Bullposting::try_update_vote(RuntimeOrigin::signed(bob), post_url.clone(), 400, crate::Direction::Bullish));
+        assert_eq!(freeze_of(bob), 400);
+        assert_eq!(amounts_of(bob), vec![300, 400]);
+
+        // Unfreezing one post's votes leaves the other vote frozen
+        System::set_block_number(voting_period + appeal_period + 1);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url));
+        assert_eq!(freeze_of(bob), 300);
+        assert_eq!(amounts_of(bob), vec![300]);
+
+        // And unfreezing the last one removes the freeze
+        System::set_block_number(voting_period + appeal_period + 2);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_2_url.clone()));
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_2_url));
+        assert_eq!(freeze_of(bob), 0);
+        assert!(!crate::VoteAmounts::<Test>::contains_key(bob));
+    });
+}
+
+#[test]
+fn test_migrate_v4_to_v5() {
+    new_test_ext().execute_with(|| {
+        let bob = 1;
+        let charlie = 2;
+        let post_id = sp_io::hashing::blake2_256(b"one post");
+        let post_2_id = sp_io::hashing::blake2_256(b"two post");
+        let post_3_id = sp_io::hashing::blake2_256(b"red post");
+
+        // Pretend the chain is still on version 4, before vote amounts were tracked
+        StorageVersion::new(4).put::<Bullposting>();
+        crate::Votes::<Test>::insert(bob, post_id, (500, crate::Direction::Bullish));
+        crate::Votes::<Test>::insert(bob, post_2_id, (300, crate::Direction::Bearish));
+        crate::Votes::<Test>::insert(charlie, post_3_id, (200, crate::Direction::Tie));
+
+        // A step that can't afford a single vote fails without making progress
+        let mut meter = WeightMeter::with_limit(Weight::zero());
+        assert!(matches!(
+            LazyMigrationV4ToV5::<Test>::step(None, &mut meter),
+            Err(SteppedMigrationError::InsufficientWeight { .. })
+        ));
+
+        // With enough weight for one vote, each step adds one vote
+        let limit = <() as WeightInfo>::migrate_v4_to_v5_step();
+        let mut cursor = None;
+        let mut steps = 0;
+        loop {
+            let mut meter = WeightMeter::with_limit(limit);
+            cursor = LazyMigrationV4ToV5::<Test>::step(cursor, &mut meter).unwrap();
+            steps += 1;
+            if cursor.is_none() {
+                break;
+            }
+            assert_eq!(Bullposting::on_chain_storage_version(), StorageVersion::new(4));
+        }
+        assert_eq!(steps, 4);
+
+        assert_eq!(Bullposting::on_chain_storage_version(), StorageVersion::new(5));
+        let mut amounts = crate::VoteAmounts::<Test>::get(bob).into_inner();
+        amounts.sort();
+        assert_eq!(amounts, vec![300, 500]);
+        assert_eq!(crate::VoteAmounts::<Test>::get(charlie).into_inner(), vec![200]);
+    });
+}
+
+#[test]
+fn test_submit_and_vote_origins() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let bond = 300;
+        let post_url: Vec<u8> = "https://example.com/members-only".into();
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        // Only members of the board can submit posts and vote
+        Members::set(Some(vec![alice, charlie]));
+
+        assert_noop!(Bullposting::try_submit_post(RuntimeOrigin::signed(bob), post_url.clone(), bond), sp_runtime::DispatchError::BadOrigin);
+        assert_noop!(Bullposting::try_submit_post(RuntimeOrigin::root(), post_url.clone(), bond), sp_runtime::DispatchError::BadOrigin);
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+
+        assert_noop!(
+            Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 100, crate::Direction::Bullish),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Bullposting::try_submit_votes(RuntimeOrigin::signed(bob), vec![(post_url.clone(), 100, crate::Direction::Bullish)]),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 100, crate::Direction::Bullish));
+
+        assert_noop!(
+            Bullposting::try_update_vote(RuntimeOrigin::signed(bob), post_url.clone(), 200, crate::Direction::Bearish),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Bullposting::try_update_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 200, crate::Direction::Bearish));
+
+        // Accounts removed from the board can no longer update their votes
+        Members::set(Some(vec![alice]));
+        assert_noop!(
+            Bullposting::try_update_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 300, crate::Direction::Bullish),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn test_report_post() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let bond = 300;
+        let report_deposit = 20;
+        let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        let empty_post: Vec<u8> = "".into();
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        // Cannot report an empty post
+        assert_noop!(Bullposting::report_post(RuntimeOrigin::signed(bob), empty_post), Error::<Test>::Empty);
+
+        // Cannot report a post that does not exist
+        assert_noop!(Bullposting::report_post(RuntimeOrigin::signed(bob), post_url.clone()), Error::<Test>::PostDoesNotExist);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+
+        // Call success with storage and event
+        assert_ok!(Bullposting::report_post(RuntimeOrigin::signed(bob), post_url.clone()));
+        assert_eq!(crate::Reports::<Test>::get(post_id, bob), Some(report_deposit));
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::ReportDeposit.into(), &bob), report_deposit);
+        System::assert_last_event(
+            Event::PostReported {
+                id: post_id,
+                reporter: bob,
+                deposit: report_deposit,
+            }.into()
+        );
+
+        // Cannot report the same post twice
+        assert_noop!(Bullposting::report_post(RuntimeOrigin::signed(bob), post_url.clone()), Error::<Test>::AlreadyReported);
+
+        // The deposit is refunded when the post resolves without being removed
+        System::set_block_number(1102);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url));
+        assert!(!crate::Reports::<Test>::contains_key(post_id, bob));
+        assert_eq!(Balances::total_balance_on_hold(&bob), 0);
+        System::assert_has_event(
+            Event::ReportRefunded {
+                id: post_id,
+                reporter: bob,
+                deposit: report_deposit,
+            }.into()
+        );
+    });
+}
+
+#[test]
+fn test_force_remove_post_upheld() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let bond = 300;
+        let balance = 1001;
+        let report_deposit = 20;
+        let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 500, crate::Direction::Bullish));
+        assert_ok!(Bullposting::report_post(RuntimeOrigin::signed(bob), post_url.clone()));
+
+        // Only the `ModeratorOrigin` can remove posts
+        assert_noop!(
+            Bullposting::force_remove_post(RuntimeOrigin::signed(bob), post_url.clone(), crate::ModerationDecision::Upheld),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // Removing the post slashes the bond and ends the post before its voting period is over
+        assert_ok!(Bullposting::force_remove_post(RuntimeOrigin::root(), post_url.clone(), crate::ModerationDecision::Upheld));
+        System::assert_last_event(
+            Event::PostRemoved {
+                id: post_id,
+                submitter: alice,
+                decision: crate::ModerationDecision::Upheld,
+                slashed: bond,
+            }.into()
+        );
+        assert_eq!(Balances::total_balance(&alice), balance - bond);
+        assert_eq!(crate::Posts::<Test>::get(post_id).unwrap().result, Some(crate::Direction::Bearish));
+
+        // Removed posts cannot be voted on, reported, ended or removed again
+        assert_noop!(Bullposting::try_update_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 400, crate::Direction::Bullish), Error::<Test>::VotingEnded);
+        assert_noop!(Bullposting::report_post(RuntimeOrigin::signed(charlie), post_url.clone()), Error::<Test>::PostAlreadyRemoved);
+        assert_noop!(Bullposting::try_end_post(RuntimeOrigin::signed(charlie), post_url.clone()), Error::<Test>::VotingStillOngoing);
+        assert_noop!(
+            Bullposting::force_remove_post(RuntimeOrigin::root(), post_url.clone(), crate::ModerationDecision::Upheld),
+            Error::<Test>::PostAlreadyRemoved
+        );
+
+        // Resolving unfreezes the votes, refunds the reporter and clears the post
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(charlie), post_url));
+        System::assert_has_event(
+            Event::ReportRefunded {
+                id: post_id,
+                reporter: bob,
+                deposit: report_deposit,
+            }.into()
+        );
+        assert_eq!(Balances::total_balance(&bob), balance);
+        assert_eq!(Balances::balance_frozen(&crate::FreezeReason::Vote.into(), &charlie), 0);
+        assert_eq!(Balances::total_balance_on_hold(&alice), 0);
+        assert!(!crate::Posts::<Test>::contains_key(post_id));
+        assert!(!crate::Removals::<Test>::contains_key(post_id));
+    });
+}
+
+#[test]
+fn test_force_remove_post_dismissed() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let bond = 300;
+        let balance = 1001;
+        let report_deposit = 20;
+        let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::report_post(RuntimeOrigin::signed(bob), post_url.clone()));
+
+        // Dismissing the reports releases the bond
+        assert_ok!(Bullposting::force_remove_post(RuntimeOrigin::root(), post_url.clone(), crate::ModerationDecision::Dismissed));
+        System::assert_last_event(
+            Event::PostRemoved {
+                id: post_id,
+                submitter: alice,
+                decision: crate::ModerationDecision::Dismissed,
+                slashed: 0,
+            }.into()
+        );
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::PostBond.into(), &alice), 0);
+
+        // Resolving slashes the reporter
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url));
+        System::assert_has_event(
+            Event::ReportSlashed {
+                id: post_id,
+                reporter: bob,
+                deposit: report_deposit,
+            }.into()
+        );
+        assert_eq!(Balances::total_balance(&alice), balance);
+        assert_eq!(Balances::total_balance(&bob), balance - report_deposit);
+        assert!(!crate::Posts::<Test>::contains_key(post_id));
+    });
+}
+
+#[test]
+fn test_migrate_v2_to_v3() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let post_id = sp_io::hashing::blake2_256(b"one post");
+        let old_post = v2::OldPost {
+            submitter: alice,
+            bond: 300,
+            bull_votes: 500,
+            bear_votes: 100,
+            submitted_at: 500,
+            voting_until: 1500,
+            result: Some(crate::Direction::Bullish),
+        };
+
+        let post_2_id = sp_io::hashing::blake2_256(b"another post");
+
+        // Pretend the chain is still on the version 2 layout
+        StorageVersion::new(2).put::<Bullposting>();
+        v2::Posts::<Test>::insert(post_id, old_post.clone());
+        v2::Posts::<Test>::insert(post_2_id, v2::OldPost { result: None, ..old_post });
+
+        // A step that can't afford a single post fails without making progress
+        let mut meter = WeightMeter::with_limit(Weight::zero());
+        assert!(matches!(
+            LazyMigrationV2ToV3::<Test>::step(None, &mut meter),
+            Err(SteppedMigrationError::InsufficientWeight { .. })
+        ));
+
+        // With enough weight for one post, each step translates one post
+        let limit = <() as WeightInfo>::migrate_v2_to_v3_step();
+        let mut cursor = None;
+        let mut steps = 0;
+        loop {
+            let mut meter = WeightMeter::with_limit(limit);
+            cursor = LazyMigrationV2ToV3::<Test>::step(cursor, &mut meter).unwrap();
+            steps += 1;
+            if cursor.is_none() {
+                break;
+            }
+            assert_eq!(Bullposting::on_chain_storage_version(), StorageVersion::new(2));
+        }
+        assert_eq!(steps, 3);
+
+        assert_eq!(Bullposting::on_chain_storage_version(), StorageVersion::new(3));
+        assert_eq!(crate::Posts::<Test>::get(post_id), Some(crate::Post {
+            submitter: alice,
+            bond: 300,
+            bull_votes: 500,
+            bear_votes: 100,
+            submitted_at: 500,
+            voting_until: 1500,
+            result: Some(crate::Direction::Bullish),
+            rounds: Default::default(),
+        }));
+        assert_eq!(crate::Posts::<Test>::get(post_2_id).map(|post| post.result), Some(None));
+    });
+}
+
+#[test]
+fn test_appeal_post() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let dave = 3;
+        let bond = 200;
+        let balance = 1001;
+        let voting_period = 1000;
+        let appeal_voting_period = 2000;
+        let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        let empty_post: Vec<u8> = "".into();
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 300, crate::Direction::Bearish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 200, crate::Direction::Bullish));
+
+        // Error if submit an empty input for the post
+        assert_noop!(Bullposting::appeal_post(RuntimeOrigin::signed(alice), empty_post, 400), Error::<Test>::Empty);
+
+        // Cannot appeal during the voting period
+        assert_noop!(Bullposting::appeal_post(RuntimeOrigin::signed(alice), post_url.clone(), 400), Error::<Test>::VotingStillOngoing);
+
+        System::set_block_number(voting_period + 1);
+
+        // The appeal bond must be at least `AppealBondCoefficient` of the post's bond
+        assert_noop!(Bullposting::appeal_post(RuntimeOrigin::signed(alice), post_url.clone(), 399), Error::<Test>::AppealBondTooLow);
+
+        // Call success with storage and event
+        assert_ok!(Bullposting::appeal_post(RuntimeOrigin::signed(alice), post_url.clone(), 400));
+        let post = crate::Posts::<Test>::get(post_id).unwrap();
+        assert_eq!(post.voting_until, voting_period + 1 + appeal_voting_period);
+        assert_eq!(post.result, None);
+        assert_eq!(post.rounds.to_vec(), vec![crate::Round {
+            appellant: alice,
+            bond: 400,
+            bull_votes: 200,
+            bear_votes: 300,
+            voting_until: voting_period + 1,
+            result: crate::Direction::Bearish,
+        }]);
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::AppealBond.into(), &alice), 400);
+        System::assert_last_event(
+            Event::PostAppealed {
+                id: post_id,
+                appellant: alice,
+                bond: 400,
+                round: 1,
+                voting_until: voting_period + 1 + appeal_voting_period,
+            }.into()
+        );
+
+        // Votes in the appeal round must be at least `AppealVoteMinimum`
+        assert_noop!(Bullposting::try_submit_vote(RuntimeOrigin::signed(dave), post_url.clone(), 50, crate::Direction::Bullish), Error::<Test>::VoteTooLow);
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(dave), post_url.clone(), 500, crate::Direction::Bullish));
+
+        // Earlier votes carry over and can still be updated
+        assert_ok!(Bullposting::try_update_vote(RuntimeOrigin::signed(bob), post_url.clone(), 400, crate::Direction::Bearish));
+
+        // The final round cannot be appealed, so the post can be ended as soon as its voting is over
+        System::set_block_number(voting_period + 1 + appeal_voting_period);
+        assert_noop!(Bullposting::appeal_post(RuntimeOrigin::signed(bob), post_url.clone(), 800), Error::<Test>::MaxAppealsReached);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
+
+        // The verdict flipped, so the appellant is refunded and the submitter rewarded
+        System::assert_has_event(
+            Event::AppealSettled {
+                id: post_id,
+                appellant: alice,
+                refunded: 400,
+                slashed: 0,
+            }.into()
+        );
+        System::assert_last_event(
+            Event::PostEnded {
+                id: post_id,
+                submitter: alice,
+                result: crate::Direction::Bullish,
+                rewarded: bond,
+                slashed: 0,
+            }.into()
+        );
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::AppealBond.into(), &alice), 0);
+
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url));
+        assert_eq!(Balances::total_balance(&alice), balance + bond);
+    });
+}
+
+#[test]
+fn test_appeal_post_upheld_verdict() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let bond = 200;
+        let balance = 1001;
+        let voting_period = 1000;
+        let appeal_period = 100;
+        let appeal_voting_period = 2000;
+        let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 300, crate::Direction::Bullish));
+
+        // Cannot appeal once the appeal period is over
+        System::set_block_number(voting_period + 1 + appeal_period);
+        assert_noop!(Bullposting::appeal_post(RuntimeOrigin::signed(charlie), post_url.clone(), 400), Error::<Test>::AppealWindowClosed);
+
+        // Any account can appeal
+        System::set_block_number(voting_period + appeal_period);
+        assert_ok!(Bullposting::appeal_post(RuntimeOrigin::signed(charlie), post_url.clone(), 400));
+
+        // Nothing changes in the appeal round, so the verdict stands and the appellant is slashed
+        System::set_block_number(voting_period + appeal_period + appeal_voting_period);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
+        System::assert_has_event(
+            Event::AppealSettled {
+                id: post_id,
+                appellant: charlie,
+                refunded: 0,
+                slashed: 400,
+            }.into()
+        );
+        assert_eq!(Balances::total_balance(&charlie), balance - 400);
+        assert_eq!(Balances::total_balance(&alice), balance + bond);
+    });
+}
+
+#[test]
+fn test_try_submit_votes() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let bond = 200;
+        let vote_amount = 500;
+        let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        let post_2_url: Vec<u8> = "testingtestingblahblah".into();
+        let post_2_id = sp_io::hashing::blake2_256(&post_2_url);
+        let fake_post_url: Vec<u8> = "get rekt kid".into();
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_2_url.clone(), bond));
+
+        // Error if the batch is larger than `MaxBatchSize`
+        let too_many = vec![(post_url.clone(), vote_amount, crate::Direction::Bullish); 6];
+        assert_noop!(Bullposting::try_submit_votes(RuntimeOrigin::signed(bob), too_many), Error::<Test>::BatchTooLarge);
+
+        // Error if the batch is empty
+        assert_noop!(Bullposting::try_submit_votes(RuntimeOrigin::signed(bob), vec![]), Error::<Test>::EmptyBatch);
+
+        // If any vote fails, none of them are applied
+        assert_noop!(
+            Bullposting::try_submit_votes(RuntimeOrigin::signed(bob), vec![
+                (post_url.clone(), vote_amount, crate::Direction::Bullish),
+                (fake_post_url, vote_amount, crate::Direction::Bullish),
+            ]),
+            Error::<Test>::PostDoesNotExist
+        );
+        assert_noop!(
+            Bullposting::try_submit_votes(RuntimeOrigin::signed(bob), vec![
+                (post_url.clone(), vote_amount, crate::Direction::Bullish),
+                (post_2_url.clone(), 10, crate::Direction::Bullish),
+            ]),
+            Error::<Test>::VoteTooLow
+        );
+
+        // Call success with storage and events
+        assert_ok!(Bullposting::try_submit_votes(RuntimeOrigin::signed(bob), vec![
+            (post_url.clone(), vote_amount, crate::Direction::Bullish),
+            (post_2_url.clone(), vote_amount, crate::Direction::Bearish),
+        ]));
+        assert_eq!(crate::Posts::<Test>::get(post_id).unwrap().bull_votes, vote_amount);
+        assert_eq!(crate::Posts::<Test>::get(post_2_id).unwrap().bear_votes, vote_amount);
+        assert_eq!(crate::VoteCounts::<Test>::get(post_id), Some(1));
+        assert_eq!(crate::VoteCounts::<Test>::get(post_2_id), Some(1));
+        System::assert_has_event(
+            Event::VoteSubmitted {
+                id: post_id,
+                voter: bob,
+                vote_amount,
+                direction: crate::Direction::Bullish,
+            }.into()
+        );
+        System::assert_last_event(
+            Event::VoteSubmitted {
+                id: post_2_id,
+                voter: bob,
+                vote_amount,
+                direction: crate::Direction::Bearish,
+            }.into()
+        );
+
+        // Voting twice on the same post in a batch fails like two separate votes would
+        assert_noop!(
+            Bullposting::try_submit_votes(RuntimeOrigin::signed(bob), vec![(post_url, vote_amount, crate::Direction::Bullish)]),
+            Error::<Test>::AlreadyVoted
+        );
+    });
+}
+
+#[test]
+fn test_try_end_posts_and_resolve_votings() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let bond = 200;
+        let vote_amount = 500;
+        let voting_period = 1000;
+        let appeal_period = 100;
+        let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        let post_2_url: Vec<u8> = "testingtestingblahblah".into();
+        let post_2_id = sp_io::hashing::blake2_256(&post_2_url);
+        let empty_post: Vec<u8> = "".into();
+        let fake_post_url: Vec<u8> = "get rekt kid".into();
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_2_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_votes(RuntimeOrigin::signed(bob), vec![
+            (post_url.clone(), vote_amount, crate::Direction::Bullish),
+            (post_2_url.clone(), vote_amount, crate::Direction::Bearish),
+        ]));
+
+        // Error if the batch is larger than `MaxBatchSize`
+        assert_noop!(Bullposting::try_end_posts(RuntimeOrigin::signed(bob), vec![post_url.clone(); 6]), Error::<Test>::BatchTooLarge);
+        assert_noop!(Bullposting::try_resolve_votings(RuntimeOrigin::signed(bob), vec![post_url.clone(); 6]), Error::<Test>::BatchTooLarge);
+
+        // Error if the batch is empty
+        assert_noop!(Bullposting::try_end_posts(RuntimeOrigin::signed(bob), vec![]), Error::<Test>::EmptyBatch);
+        assert_noop!(Bullposting::try_resolve_votings(RuntimeOrigin::signed(bob), vec![]), Error::<Test>::EmptyBatch);
+
+        // Failed items are reported and skipped, the others are still applied
+        System::set_block_number(voting_period + appeal_period + 1);
+        assert_ok!(Bullposting::try_end_posts(RuntimeOrigin::signed(bob), vec![
+            post_url.clone(),
+            empty_post,
+            fake_post_url.clone(),
+            post_2_url.clone(),
+        ]));
+        System::assert_has_event(
+            Event::BatchItemFailed {
+                index: 1,
+                error: Error::<Test>::Empty.into(),
+            }.into()
+        );
+        System::assert_has_event(
+            Event::BatchItemFailed {
+                index: 2,
+                error: Error::<Test>::PostDoesNotExist.into(),
+            }.into()
+        );
+        System::assert_has_event(
+            Event::PostEnded {
+                id: post_id,
+                submitter: alice,
+                result: crate::Direction::Bullish,
+                rewarded: bond,
+                slashed: 0,
+            }.into()
+        );
+        System::assert_last_event(
+            Event::PostEnded {
+                id: post_2_id,
+                submitter: alice,
+                result: crate::Direction::Bearish,
+                rewarded: 0,
+                slashed: bond,
+            }.into()
+        );
+
+        // Ending an already ended post fails for that item only, and a batch that ends nothing is charged
+        let post_info = Bullposting::try_end_posts(RuntimeOrigin::signed(bob), vec![post_url.clone()]).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::Yes);
+        System::assert_last_event(
+            Event::BatchItemFailed {
+                index: 0,
+                error: Error::<Test>::PostAlreadyEnded.into(),
+            }.into()
+        );
+
+        // A resolve batch that finishes nothing is charged too
+        let post_info = Bullposting::try_resolve_votings(RuntimeOrigin::signed(bob), vec![fake_post_url.clone()]).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::Yes);
+        System::assert_last_event(
+            Event::BatchItemFailed {
+                index: 0,
+                error: Error::<Test>::PostDoesNotExist.into(),
+            }.into()
+        );
+
+        assert_ok!(Bullposting::try_resolve_votings(RuntimeOrigin::signed(bob), vec![
+            post_url,
+            fake_post_url,
+            post_2_url,
+        ]));
+        System::assert_has_event(
+            Event::BatchItemFailed {
+                index: 1,
+                error: Error::<Test>::PostDoesNotExist.into(),
+            }.into()
+        );
+        System::assert_last_event(Event::PostResolved { id: post_2_id }.into());
+        assert!(!crate::Posts::<Test>::contains_key(post_id));
+        assert!(!crate::Posts::<Test>::contains_key(post_2_id));
+        assert_eq!(Balances::balance_frozen(&crate::FreezeReason::Vote.into(), &bob), 0);
+        assert_eq!(Balances::total_balance(&alice), 1001);
+    });
+}
+
+#[test]
+fn test_submission_rate_limits() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let bond = 50;
+        let voting_period = 1000;
+        let appeal_period = 100;
+        let cooldown = 10;
+        let post_urls: Vec<Vec<u8>> = (0..4).map(|i| format!("https://example.com/{i}").into_bytes()).collect();
+        let cooldown_urls: Vec<Vec<u8>> = (0..3).map(|i| format!("https://example.com/cooldown/{i}").into_bytes()).collect();
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        // Up to `MaxActivePosts` posts can be active at once
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_urls[0].clone(), bond));
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_urls[1].clone(), bond));
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_urls[2].clone(), bond));
+        assert_eq!(crate::Submissions::<Test>::get(alice), Some((3, 1)));
+        assert_noop!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_urls[3].clone(), bond), Error::<Test>::TooManyActivePosts);
+
+        // Other accounts are not affected
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(bob), post_urls[3].clone(), bond));
+
+        // Ended posts still count until they are resolved
+        System::set_block_number(voting_period + appeal_period + 1);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_urls[0].clone()));
+        assert_noop!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), "another post".into(), bond), Error::<Test>::TooManyActivePosts);
+
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_urls[0].clone()));
+        assert_eq!(crate::Submissions::<Test>::get(alice), Some((2, 1)));
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), "another post".into(), bond));
+
+        // Submissions must be at least `CooldownPeriod` blocks apart
+        CooldownPeriod::set(cooldown);
+        let now = System::block_number();
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(charlie), cooldown_urls[0].clone(), bond));
+        System::set_block_number(now + cooldown - 1);
+        assert_noop!(Bullposting::try_submit_post(RuntimeOrigin::signed(charlie), cooldown_urls[1].clone(), bond), Error::<Test>::SubmissionCooldown);
+        System::set_block_number(now + cooldown);
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(charlie), cooldown_urls[1].clone(), bond));
+
+        // The cooldown still applies once all of an account's posts are resolved
+        for post_url in &cooldown_urls[0..2] {
+            assert_ok!(Bullposting::force_remove_post(RuntimeOrigin::root(), post_url.clone(), crate::ModerationDecision::Dismissed));
+            assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url.clone()));
+        }
+        assert_eq!(crate::Submissions::<Test>::get(charlie), Some((0, now + cooldown)));
+        assert_noop!(Bullposting::try_submit_post(RuntimeOrigin::signed(charlie), cooldown_urls[2].clone(), bond), Error::<Test>::SubmissionCooldown);
+
+        // Once the cooldown is over, resolving the last active post removes the entry
+        System::set_block_number(now + 2 * cooldown);
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(charlie), cooldown_urls[2].clone(), bond));
+        assert_ok!(Bullposting::force_remove_post(RuntimeOrigin::root(), cooldown_urls[2].clone(), crate::ModerationDecision::Dismissed));
+        System::set_block_number(now + 3 * cooldown);
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), cooldown_urls[2].clone()));
+        assert_eq!(crate::Submissions::<Test>::get(charlie), None);
+    });
+}
+
+#[test]
+fn test_migrate_v3_to_v4() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let post = |submitter, submitted_at| crate::Post::<Test> {
+            submitter,
+            bond: 300,
+            bull_votes: 0,
+            bear_votes: 0,
+            submitted_at,
+            voting_until: submitted_at + 1000,
+            result: None,
+            rounds: Default::default(),
+        };
+
+        // Pretend the chain is still on version 3, before submissions were tracked
+        StorageVersion::new(3).put::<Bullposting>();
+        crate::Posts::<Test>::insert(sp_io::hashing::blake2_256(b"one post"), post(alice, 500));
+        crate::Posts::<Test>::insert(sp_io::hashing::blake2_256(b"two post"), post(alice, 700));
+        crate::Posts::<Test>::insert(sp_io::hashing::blake2_256(b"red post"), post(bob, 600));
+
+        // A step that can't afford a single post fails without making progress
+        let mut meter = WeightMeter::with_limit(Weight::zero());
+        assert!(matches!(
+            LazyMigrationV3ToV4::<Test>::step(None, &mut meter),
+            Err(SteppedMigrationError::InsufficientWeight { .. })
+        ));
+
+        // With enough weight for one post, each step counts one post
+        let limit = <() as WeightInfo>::migrate_v3_to_v4_step();
+        let mut cursor = None;
+        let mut steps = 0;
+        loop {
+            let mut meter = WeightMeter::with_limit(limit);
+            cursor = LazyMigrationV3ToV4::<Test>::step(cursor, &mut meter).unwrap();
+            steps += 1;
+            if cursor.is_none() {
+                break;
+            }
+            assert_eq!(Bullposting::on_chain_storage_version(), StorageVersion::new(3));
+        }
+        assert_eq!(steps, 4);
+
+        assert_eq!(Bullposting::on_chain_storage_version(), StorageVersion::new(4));
+        assert_eq!(crate::Submissions::<Test>::get(alice), Some((2, 700)));
+        assert_eq!(crate::Submissions::<Test>::get(bob), Some((1, 600)));
+    });
+}
+
+#[test]
+fn test_self_vote_policy() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let bond = 200;
+        let balance = 1001;
+        let period = 1000 + 100;
+        let post_urls: Vec<Vec<u8>> = (0..5).map(|i| format!("https://example.com/{i}").into_bytes()).collect();
+        let post_ids: Vec<[u8; 32]> = post_urls.iter().map(|url| sp_io::hashing::blake2_256(url)).collect();
+
+        // Ends and resolves a post once its voting and appeal periods are over
+        let close = |post_url: &Vec<u8>| {
+            System::set_block_number(System::block_number() + period);
+            assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
+            let ended = System::events().pop().unwrap().event;
+            assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url.clone()));
+            ended
+        };
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        // With `Allow`, a submitter can outvote everyone else on their own post and mint themselves the reward
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_urls[0].clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(alice), post_urls[0].clone(), 600, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_urls[0].clone(), 500, crate::Direction::Bearish));
+        assert_eq!(close(&post_urls[0]), Event::PostEnded {
+            id: post_ids[0],
+            submitter: alice,
+            result: crate::Direction::Bullish,
+            rewarded: bond,
+            slashed: 0,
+        }.into());
+        assert_eq!(Balances::total_balance(&alice), balance + bond);
+
+        // With `Forbid`, submitters cannot vote on their own posts or update a vote made before the policy changed
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_urls[1].clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(alice), post_urls[1].clone(), 600, crate::Direction::Bullish));
+        SelfVotePolicy::set(crate::SelfVotePolicy::Forbid);
+        assert_noop!(Bullposting::try_update_vote(RuntimeOrigin::signed(alice), post_urls[1].clone(), 700, crate::Direction::Bullish), Error::<Test>::SelfVote);
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_urls[2].clone(), bond));
+        assert_noop!(Bullposting::try_submit_vote(RuntimeOrigin::signed(alice), post_urls[2].clone(), 600, crate::Direction::Bullish), Error::<Test>::SelfVote);
+
+        // Other accounts can still vote
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_urls[2].clone(), 500, crate::Direction::Bearish));
+        close(&post_urls[1]);
+        assert_eq!(close(&post_urls[2]), Event::PostEnded {
+            id: post_ids[2],
+            submitter: alice,
+            result: crate::Direction::Bearish,
+            rewarded: 0,
+            slashed: bond,
+        }.into());
+
+        // With `ExcludeFromReward`, a submitter's vote still counts towards the verdict, but cannot earn them the reward
+        SelfVotePolicy::set(crate::SelfVotePolicy::ExcludeFromReward);
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_urls[3].clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(alice), post_urls[3].clone(), 600, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_urls[3].clone(), 500, crate::Direction::Bearish));
+        assert_eq!(close(&post_urls[3]), Event::PostEnded {
+            id: post_ids[3],
+            submitter: alice,
+            result: crate::Direction::Bullish,
+            rewarded: 0,
+            slashed: 0,
+        }.into());
+
+        // Posts that are Bullish without the submitter's vote are still rewarded
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_urls[4].clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(alice), post_urls[4].clone(), 600, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_urls[4].clone(), 500, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_urls[4].clone(), 400, crate::Direction::Bearish));
+        assert_eq!(close(&post_urls[4]), Event::PostEnded {
+            id: post_ids[4],
+            submitter: alice,
+            result: crate::Direction::Bullish,
+            rewarded: bond,
+            slashed: 0,
+        }.into());
+    });
+}
+
+#[test]
+fn test_keeper_rewards() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let dave = 3;
+        let bond = 200;
+        let balance = 1001;
+        let keeper_reward = 10;
+        let period = 1000 + 100;
+        let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        let post_2_url: Vec<u8> = "testingtestingblahblah".into();
+        let post_2_id = sp_io::hashing::blake2_256(&post_2_url);
+        let fake_post_url: Vec<u8> = "get rekt kid".into();
+
+        KeeperReward::set(10);
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 500, crate::Direction::Bearish));
+        System::set_block_number(period + 1);
+
+        // Ending the post pays the caller out of the submitter's storage rent, and is free
+        let post_info = Bullposting::try_end_post(RuntimeOrigin::signed(charlie), post_url.clone()).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        System::assert_has_event(
+            Event::KeeperRewarded {
+                id: post_id,
+                keeper: charlie,
+                amount: keeper_reward,
+            }.into()
+        );
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::StorageRent.into(), &alice), 100 - keeper_reward);
+        assert_eq!(Balances::total_balance(&charlie), balance + keeper_reward);
+
+        // Finishing the resolution pays the caller again and releases the rest of the rent
+        let post_info = Bullposting::try_resolve_voting(RuntimeOrigin::signed(dave), post_url).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        System::assert_has_event(
+            Event::KeeperRewarded {
+                id: post_id,
+                keeper: dave,
+                amount: keeper_reward,
+            }.into()
+        );
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::StorageRent.into(), &alice), 0);
+        assert_eq!(Balances::total_balance(&dave), balance + keeper_reward);
+        assert_eq!(Balances::total_balance(&alice), balance - bond - 2 * keeper_reward);
+
+        // Nobody is paid for a moderator ending a post, so that part of the rent goes back to the submitter
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(bob), post_2_url.clone(), bond));
+        assert_ok!(Bullposting::force_remove_post(RuntimeOrigin::root(), post_2_url.clone(), crate::ModerationDecision::Dismissed));
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::StorageRent.into(), &bob), 100 - keeper_reward);
+
+        // Batches are only free if every post is handled
+        let post_info = Bullposting::try_resolve_votings(RuntimeOrigin::signed(charlie), vec![fake_post_url, post_2_url]).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::Yes);
+        System::assert_last_event(Event::PostResolved { id: post_2_id }.into());
+        assert_eq!(Balances::total_balance(&charlie), balance + 2 * keeper_reward);
+        assert_eq!(Balances::total_balance(&bob), balance - keeper_reward);
+    });
+}
+
+#[test]
+fn test_force_end_and_resolve_post() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let dave = 3;
+        let bond = 200;
+        let post_url: Vec<u8> = "https://example.com/stuck".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        let missing_id = sp_io::hashing::blake2_256(b"missing");
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 100, crate::Direction::Bearish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 60, crate::Direction::Bearish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(dave), post_url.clone(), 70, crate::Direction::Bullish));
+
+        // Only root can force posts, and only posts that exist
+        assert_noop!(Bullposting::force_end_post(RuntimeOrigin::signed(alice), post_id, None), sp_runtime::DispatchError::BadOrigin);
+        assert_noop!(Bullposting::force_resolve_post(RuntimeOrigin::signed(alice), post_id, 10), sp_runtime::DispatchError::BadOrigin);
+        assert_noop!(Bullposting::force_end_post(RuntimeOrigin::root(), missing_id, None), Error::<Test>::PostDoesNotExist);
+        assert_noop!(Bullposting::force_resolve_post(RuntimeOrigin::root(), missing_id, 10), Error::<Test>::PostDoesNotExist);
+        assert_noop!(Bullposting::force_resolve_post(RuntimeOrigin::root(), post_id, 10), Error::<Test>::PostUnended);
+
+        // Root can end a post while voting is still ongoing, even with ending paused, and override the tallies
+        assert_ok!(Bullposting::pause(RuntimeOrigin::root(), crate::PauseScope::Ending));
+        assert_ok!(Bullposting::force_end_post(RuntimeOrigin::root(), post_id, Some(crate::Direction::Bullish)));
+        System::assert_last_event(
+            Event::PostForceEnded {
+                id: post_id,
+                result: crate::Direction::Bullish,
+                overridden: true,
+                failed: 0,
+            }.into()
+        );
+        assert_eq!(crate::Posts::<Test>::get(post_id).unwrap().result, Some(crate::Direction::Bullish));
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::PostBond.into(), &alice), 0);
+        assert_noop!(Bullposting::force_end_post(RuntimeOrigin::root(), post_id, None), Error::<Test>::PostAlreadyEnded);
+
+        // Resolving settles up to `max` votes at a time
+        assert_ok!(Bullposting::force_resolve_post(RuntimeOrigin::root(), post_id, 2));
+        System::assert_last_event(
+            Event::PostForceResolved {
+                id: post_id,
+                settled: 2,
+                failed: 0,
+            }.into()
+        );
+        System::assert_has_event(Event::PartiallyResolved { id: post_id }.into());
+        assert_eq!(crate::VoteCounts::<Test>::get(post_id), Some(1));
+
+        assert_ok!(Bullposting::force_resolve_post(RuntimeOrigin::root(), post_id, 2));
+        System::assert_last_event(
+            Event::PostForceResolved {
+                id: post_id,
+                settled: 1,
+                failed: 0,
+            }.into()
+        );
+        System::assert_has_event(Event::PostResolved { id: post_id }.into());
+        assert!(!crate::Posts::<Test>::contains_key(post_id));
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::StorageRent.into(), &alice), 0);
+        for voter in [bob, charlie, dave] {
+            assert_eq!(Balances::balance_frozen(&crate::FreezeReason::Vote.into(), &voter), 0);
+        }
+
+        // A stake that cannot be settled is refunded, rather than left held for a vote that is gone
+        Parimutuel::set(true);
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 100, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 60, crate::Direction::Bearish));
+        assert_ok!(Bullposting::force_end_post(RuntimeOrigin::root(), post_id, None));
+
+        // Bob's winnings cannot be minted once the total issuance is at its limit
+        pallet_balances::TotalIssuance::<Test>::put(Balance::MAX);
+        assert_ok!(Bullposting::force_resolve_post(RuntimeOrigin::root(), post_id, 10));
+        System::assert_last_event(
+            Event::PostForceResolved {
+                id: post_id,
+                settled: 2,
+                failed: 1,
+            }.into()
+        );
+        assert!(!crate::Votes::<Test>::contains_key(bob, post_id));
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::VoteStake.into(), &bob), 0);
+        assert_eq!(Balances::total_balance(&bob), 1001);
+        assert_eq!(Balances::total_balance(&charlie), 1001 - 60);
+    });
+}
+
+#[test]
+fn test_post_outcomes() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let bond = 200;
+        let period = 1000 + 100;
+        let post_url: Vec<u8> = "https://example.com/archived".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        let removed_url: Vec<u8> = "https://example.com/removed".into();
+        let removed_id = sp_io::hashing::blake2_256(&removed_url);
+        let outcome = |bull_votes, bear_votes, result, rewarded, slashed, ended_at| crate::PostOutcome::<Test> {
+            submitter: alice,
+            bull_votes,
+            bear_votes,
+            result,
+            rewarded,
+            slashed,
+            ended_at,
+        };
+
+        OutcomeRetention::set(50);
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), removed_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 300, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 100, crate::Direction::Bearish));
+
+        // A moderator removing a post records it as ended
+        assert_ok!(Bullposting::force_remove_post(RuntimeOrigin::root(), removed_url, crate::ModerationDecision::Upheld));
+        assert_eq!(crate::PostOutcomes::<Test>::get(removed_id), Some(outcome(0, 0, crate::Direction::Bearish, 0, bond, 1)));
+
+        // The outcome is recorded when the post is ended and kept once it is resolved
+        System::set_block_number(period + 1);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url));
+        assert!(!crate::Posts::<Test>::contains_key(post_id));
+        assert_eq!(crate::PostOutcomes::<Test>::get(post_id), Some(outcome(300, 100, crate::Direction::Bullish, bond, 0, period + 1)));
+
+        // Outcomes are pruned once `OutcomeRetention` has passed
+        Bullposting::on_initialize(51);
+        assert!(!crate::PostOutcomes::<Test>::contains_key(removed_id));
+        Bullposting::on_initialize(period + 50);
+        assert!(crate::PostOutcomes::<Test>::contains_key(post_id));
+        Bullposting::on_initialize(period + 51);
+        assert!(!crate::PostOutcomes::<Test>::contains_key(post_id));
+        assert_eq!(crate::OutcomeExpiries::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn test_parimutuel_votes() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let dave = 3;
+        let bond = 200;
+        let balance = 1001;
+        let period = 1000 + 100;
+        let post_url: Vec<u8> = "https://example.com/market".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        let tied_url: Vec<u8> = "https://example.com/tied".into();
+        let tied_id = sp_io::hashing::blake2_256(&tied_url);
+        let removed_url: Vec<u8> = "https://example.com/removed".into();
+        let removed_id = sp_io::hashing::blake2_256(&removed_url);
+        let stake_of = |who| Balances::balance_on_hold(&crate::HoldReason::VoteStake.into(), &who);
+
+        Parimutuel::set(true);
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), tied_url.clone(), bond));
+
+        // Votes are held as stakes rather than frozen, and each vote is staked separately
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 300, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 100, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(dave), post_url.clone(), 200, crate::Direction::Bearish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), tied_url.clone(), 100, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(dave), tied_url.clone(), 100, crate::Direction::Bearish));
+        assert_eq!(stake_of(bob), 400);
+        assert_eq!(Balances::balance_frozen(&crate::FreezeReason::Vote.into(), &bob), 0);
+
+        // Updating a vote holds or releases the difference
+        assert_ok!(Bullposting::try_update_vote(RuntimeOrigin::signed(bob), post_url.clone(), 250, crate::Direction::Bullish));
+        assert_eq!(stake_of(bob), 350);
+        assert_ok!(Bullposting::try_update_vote(RuntimeOrigin::signed(bob), post_url.clone(), 300, crate::Direction::Bullish));
+        assert_eq!(stake_of(bob), 400);
+
+        // Stakes can only come out of free tokens
+        assert_noop!(
+            Bullposting::try_update_vote(RuntimeOrigin::signed(dave), post_url.clone(), 950, crate::Direction::Bearish),
+            Error::<Test>::InsufficientFreeBalance
+        );
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), removed_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), removed_url.clone(), 100, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(dave), removed_url.clone(), 50, crate::Direction::Bearish));
+
+        System::set_block_number(period + 1);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), tied_url.clone()));
+
+        // The Bearish stake, less the 10% fee, is shared among the Bullish voters by stake
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url));
+        System::assert_has_event(Event::StakeSettled { id: post_id, voter: bob, stake: 300, winnings: 135, lost: 0 }.into());
+        System::assert_has_event(Event::StakeSettled { id: post_id, voter: charlie, stake: 100, winnings: 45, lost: 0 }.into());
+        System::assert_has_event(Event::StakeSettled { id: post_id, voter: dave, stake: 200, winnings: 0, lost: 200 }.into());
+        assert_eq!(Balances::total_balance(&charlie), balance + 45);
+        assert_eq!(Balances::total_balance(&dave), balance - 200);
+
+        // Everyone is refunded on a tie
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), tied_url));
+        System::assert_has_event(Event::StakeSettled { id: tied_id, voter: bob, stake: 100, winnings: 0, lost: 0 }.into());
+        System::assert_has_event(Event::StakeSettled { id: tied_id, voter: dave, stake: 100, winnings: 0, lost: 0 }.into());
+
+        // A moderator's removal is not the market's verdict, so everyone is refunded even though the removal counts as Bearish
+        assert_ok!(Bullposting::force_remove_post(RuntimeOrigin::root(), removed_url.clone(), crate::ModerationDecision::Upheld));
+        assert_eq!(crate::Posts::<Test>::get(removed_id).unwrap().result, Some(crate::Direction::Bearish));
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), removed_url));
+        System::assert_has_event(Event::StakeSettled { id: removed_id, voter: bob, stake: 100, winnings: 0, lost: 0 }.into());
+        System::assert_has_event(Event::StakeSettled { id: removed_id, voter: dave, stake: 50, winnings: 0, lost: 0 }.into());
+        for voter in [bob, charlie, dave] {
+            assert_eq!(stake_of(voter), 0);
+        }
+        assert_eq!(Balances::total_balance(&bob), balance + 135);
+        assert_eq!(Balances::total_balance(&dave), balance - 200);
+    });
+}
+
+#[test]
+fn test_voter_records_and_accuracy_weighting() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let charlie = 2;
+        let dave = 3;
+        let bond = 200;
+        let period = 1000 + 100;
+        let post_url: Vec<u8> = "https://example.com/first".into();
+        let post_2_url: Vec<u8> = "https://example.com/second".into();
+        let post_2_id = sp_io::hashing::blake2_256(&post_2_url);
+        let record = |correct, incorrect, ties, resolved_at| crate::VoterRecord { correct, incorrect, ties, resolved_at };
+
+        // Votes count between half and one and a half times their size
+        AccuracyWeight::set(sp_runtime::Percent::from_percent(50));
+        OutcomeRetention::set(50);
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        // Voters without a record count for the least
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 100, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 100, crate::Direction::Bearish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(dave), post_url.clone(), 100, crate::Direction::Tie));
+        let post = crate::Posts::<Test>::get(sp_io::hashing::blake2_256(&post_url)).unwrap();
+        assert_eq!((post.bull_votes, post.bear_votes), (50, 50));
+        assert_eq!(crate::VoteWeights::<Test>::iter().count(), 3);
+
+        // Records are kept as the votes are resolved
+        System::set_block_number(period + 1);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
+        assert!(!crate::VoterRecords::<Test>::contains_key(bob));
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url.clone()));
+        assert_eq!(crate::VoterRecords::<Test>::get(bob), record(0, 0, 1, period + 1));
+        assert_eq!(crate::VoterRecords::<Test>::get(dave), record(0, 0, 1, period + 1));
+
+        // Voters with only Tie results have no accuracy either
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 100, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_url.clone(), 100, crate::Direction::Bearish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(dave), post_url.clone(), 100, crate::Direction::Bearish));
+        let post = crate::Posts::<Test>::get(sp_io::hashing::blake2_256(&post_url)).unwrap();
+        assert_eq!((post.bull_votes, post.bear_votes), (50, 100));
+        System::set_block_number(2 * period + 2);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url.clone()));
+        assert_eq!(crate::VoterRecords::<Test>::get(bob), record(0, 1, 1, 2 * period + 2));
+        assert_eq!(crate::VoterRecords::<Test>::get(charlie), record(1, 0, 1, 2 * period + 2));
+        assert_eq!(crate::VoterRecords::<Test>::get(dave), record(1, 0, 1, 2 * period + 2));
+
+        // Accurate voters count for more, and inaccurate voters for less
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_2_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_2_url.clone(), 200, crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(charlie), post_2_url.clone(), 100, crate::Direction::Bearish));
+        let post = crate::Posts::<Test>::get(post_2_id).unwrap();
+        assert_eq!((post.bull_votes, post.bear_votes), (100, 150));
+        assert_eq!(crate::VoteWeights::<Test>::get(post_2_id, bob), Some(100));
+
+        // Updating a vote takes its old weight out of the tallies
+        assert_ok!(Bullposting::try_update_vote(RuntimeOrigin::signed(bob), post_2_url.clone(), 400, crate::Direction::Bullish));
+        let post = crate::Posts::<Test>::get(post_2_id).unwrap();
+        assert_eq!((post.bull_votes, post.bear_votes), (200, 150));
+
+        System::set_block_number(3 * period + 3);
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_2_url.clone()));
+        assert_eq!(crate::Posts::<Test>::get(post_2_id).unwrap().result, Some(crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_2_url));
+        assert_eq!(crate::VoterRecords::<Test>::get(bob), record(1, 1, 1, 3 * period + 3));
+        assert_eq!(crate::VoterRecords::<Test>::get(charlie), record(1, 1, 1, 3 * period + 3));
+        assert_eq!(crate::VoteWeights::<Test>::iter().count(), 0);
+
+        // Records are pruned once `OutcomeRetention` has passed since their last resolved vote
+        Bullposting::on_initialize(period + 51);
+        assert!(crate::VoterRecords::<Test>::contains_key(dave));
+        Bullposting::on_initialize(2 * period + 52);
+        assert!(!crate::VoterRecords::<Test>::contains_key(dave));
+        assert!(crate::VoterRecords::<Test>::contains_key(bob));
+        Bullposting::on_initialize(3 * period + 53);
+        assert_eq!(crate::VoterRecords::<Test>::iter().count(), 0);
+        assert_eq!(crate::VoterRecordExpiries::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn test_genesis_config() {
+    let alice = 0;
+    let bob = 1;
+    let charlie = 2;
+    let balance = 1001;
+    let bond = 300;
+    let post_url: Vec<u8> = "https://example.com/genesis/1".into();
+    let post_id = sp_io::hashing::blake2_256(&post_url);
+    let post_2_url: Vec<u8> = "https://example.com/genesis/2".into();
+    let post_2_id = sp_io::hashing::blake2_256(&post_2_url);
+
+    let genesis = crate::GenesisConfig::<Test> {
+        posts: vec![
+            (alice, post_url.clone(), bond, 10),
+            (alice, post_2_url.clone(), bond, 20),
+        ],
+        votes: vec![
+            (bob, post_url.clone(), 100, crate::Direction::Bullish),
+            (charlie, post_url.clone(), 60, crate::Direction::Bearish),
+            (bob, post_2_url.clone(), 200, crate::Direction::Bearish),
+        ],
+        attesters: vec![charlie],
+    };
+
+    new_test_ext_with_genesis(vec![(alice, balance), (bob, balance), (charlie, balance)], genesis).execute_with(|| {
+        assert_ok!(Bullposting::do_try_state());
+
+        // Posts hold the bond and rent like submitted ones, without the `CooldownPeriod` applying between them
+        let post = crate::Posts::<Test>::get(post_id).unwrap();
+        assert_eq!(post.submitter, alice);
+        assert_eq!((post.bull_votes, post.bear_votes), (100, 60));
+        assert_eq!(post.voting_until, 10);
+        assert_eq!(crate::Posts::<Test>::get(post_2_id).unwrap().voting_until, 20);
+        assert_eq!(crate::Submissions::<Test>::get(alice), Some((2, 0)));
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::PostBond.into(), &alice), 2 * bond);
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::StorageRent.into(), &alice), 2 * 100);
+
+        // Votes are frozen and counted
+        assert_eq!(Balances::balance_frozen(&crate::FreezeReason::Vote.into(), &bob), 200);
+        assert_eq!(Balances::balance_frozen(&crate::FreezeReason::Vote.into(), &charlie), 60);
+        assert_eq!(crate::VoteCounts::<Test>::get(post_id), Some(2));
+
+        assert_eq!(crate::Attesters::<Test>::get().into_inner(), vec![charlie]);
+
+        // Genesis posts end and resolve like any other
+        System::set_block_number(10 + <Test as crate::Config>::AppealPeriod::get());
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(charlie), post_url.clone()));
+        assert_eq!(crate::Posts::<Test>::get(post_id).unwrap().result, Some(crate::Direction::Bullish));
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(charlie), post_url));
+        assert_eq!(Balances::balance_frozen(&crate::FreezeReason::Vote.into(), &charlie), 0);
+    });
+}
+
+#[test]
+#[should_panic(expected = "Genesis votes must be valid votes on genesis posts")]
+fn test_genesis_config_vote_on_missing_post() {
+    let genesis = crate::GenesisConfig::<Test> {
+        posts: vec![],
+        votes: vec![(1, "https://example.com/genesis".into(), 100, crate::Direction::Bullish)],
+        ..Default::default()
+    };
+    new_test_ext_with_genesis(vec![(1, 1001)], genesis);
+}
+
+#[test]
+fn test_offchain_worker_ends_and_resolves_posts() {
+    let mut ext = new_test_ext();
+    let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let bond = 200;
+        let balance = 1001;
+        let period = 1000 + 100;
+        let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        let end_call = crate::Call::<Test>::auto_end_post { id: post_id };
+        let resolve_call = crate::Call::<Test>::auto_resolve_voting { id: post_id };
+        // The calls the offchain worker has submitted to the transaction pool so far
+        let submitted = || -> Vec<RuntimeCall> {
+            pool_state.read().transactions.iter()
+                .map(|tx| Extrinsic::decode(&mut &tx[..]).unwrap().function)
+                .collect()
+        };
+
+        KeeperReward::set(10);
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 500, crate::Direction::Bearish));
+
+        // Nothing is submitted while the post can still be voted on or appealed
+        Bullposting::offchain_worker(2);
+        assert!(submitted().is_empty());
+        assert_eq!(
+            Bullposting::validate_unsigned(TransactionSource::External, &end_call),
+            InvalidTransaction::Future.into()
+        );
+
+        // Once it can be ended, the post is ended once, however many times the offchain worker runs
+        System::set_block_number(period + 1);
+        Bullposting::offchain_worker(period + 1);
+        Bullposting::offchain_worker(period + 1);
+        assert_eq!(submitted(), vec![end_call.clone().into()]);
+        assert!(Bullposting::validate_unsigned(TransactionSource::External, &end_call).is_ok());
+        assert_eq!(
+            Bullposting::validate_unsigned(TransactionSource::External, &resolve_call),
+            InvalidTransaction::Future.into()
+        );
+
+        // Only unsigned transactions can end a post without a keeper, whose reward stays with the submitter
+        assert_noop!(Bullposting::auto_end_post(RuntimeOrigin::signed(bob), post_id), frame_support::error::BadOrigin);
+        assert_ok!(Bullposting::auto_end_post(RuntimeOrigin::none(), post_id));
+        assert!(!System::events().iter().any(|record| matches!(record.event, RuntimeEvent::Bullposting(Event::KeeperRewarded { .. }))));
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::StorageRent.into(), &alice), 100 - 10);
+        assert_eq!(
+            Bullposting::validate_unsigned(TransactionSource::External, &end_call),
+            InvalidTransaction::Stale.into()
+        );
+
+        // The ended post is resolved on the next run
+        System::set_block_number(period + 2);
+        Bullposting::offchain_worker(period + 2);
+        assert_eq!(submitted(), vec![end_call.into(), resolve_call.into()]);
+        assert_ok!(Bullposting::auto_resolve_voting(RuntimeOrigin::none(), post_id));
+        System::assert_last_event(Event::PostResolved { id: post_id }.into());
+        assert_eq!(Balances::balance_on_hold(&crate::HoldReason::StorageRent.into(), &alice), 0);
+        assert_eq!(Balances::total_balance(&alice), balance - bond);
+        assert_eq!(Balances::total_balance(&bob), balance);
+    });
+}
+
+#[test]
+fn test_pause_and_unpause() {
+    new_test_ext().execute_with(|| {
+        let alice = 0;
+        let bob = 1;
+        let bond = 200;
+        let post_url: Vec<u8> = "https://example.com/paused".into();
+        let other_url: Vec<u8> = "https://example.com/other".into();
+        let paused = |submissions, voting, ending| crate::PauseStatus { submissions, voting, ending };
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+
+        // Only the `PauseOrigin` can pause and unpause calls
+        assert_noop!(Bullposting::pause(RuntimeOrigin::signed(alice), crate::PauseScope::All), sp_runtime::DispatchError::BadOrigin);
+        assert_noop!(Bullposting::unpause(RuntimeOrigin::signed(alice), crate::PauseScope::All), sp_runtime::DispatchError::BadOrigin);
+
+        // Pausing submissions leaves voting open
+        assert_ok!(Bullposting::pause(RuntimeOrigin::root(), crate::PauseScope::Submissions));
+        System::assert_last_event(Event::Paused { scope: crate::PauseScope::Submissions }.into());
+        assert_eq!(crate::PalletStatus::<Test>::get(), paused(true, false, false));
+        assert_noop!(Bullposting::try_submit_post(RuntimeOrigin::signed(bob), other_url.clone(), bond), Error::<Test>::Paused);
+        assert_ok!(Bullposting::try_submit_vote(RuntimeOrigin::signed(bob), post_url.clone(), 100, crate::Direction::Bullish));
+
+        // Pausing voting stops new and updated votes, batches and appeals
+        assert_ok!(Bullposting::pause(RuntimeOrigin::root(), crate::PauseScope::Voting));
+        assert_eq!(crate::PalletStatus::<Test>::get(), paused(true, true, false));
+        assert_noop!(Bullposting::try_update_vote(RuntimeOrigin::signed(bob), post_url.clone(), 200, crate::Direction::Bullish), Error::<Test>::Paused);
+        assert_noop!(
+            Bullposting::try_submit_votes(RuntimeOrigin::signed(alice), vec![(post_url.clone(), 100, crate::Direction::Bullish)]),
+            Error::<Test>::Paused
+        );
+        System::set_block_number(1001);
+        assert_noop!(Bullposting::appeal_post(RuntimeOrigin::signed(alice), post_url.clone(), 400), Error::<Test>::Paused);
+
+        // Pausing everything also stops ending and resolving posts
+        System::set_block_number(1101);
+        assert_ok!(Bullposting::pause(RuntimeOrigin::root(), crate::PauseScope::All));
+        System::assert_last_event(Event::Paused { scope: crate::PauseScope::All }.into());
+        assert!(crate::PalletStatus::<Test>::get().is_paused(crate::PauseScope::All));
+        assert_noop!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()), Error::<Test>::Paused);
+        assert_noop!(Bullposting::try_end_posts(RuntimeOrigin::signed(bob), vec![post_url.clone()]), Error::<Test>::Paused);
+        assert_noop!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url.clone()), Error::<Test>::Paused);
+        assert_noop!(Bullposting::try_resolve_votings(RuntimeOrigin::signed(bob), vec![post_url.clone()]), Error::<Test>::Paused);
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        assert_noop!(Bullposting::auto_end_post(RuntimeOrigin::none(), post_id), Error::<Test>::Paused);
+        assert_noop!(Bullposting::auto_resolve_voting(RuntimeOrigin::none(), post_id), Error::<Test>::Paused);
+
+        // Reports are never paused
+        assert_ok!(Bullposting::report_post(RuntimeOrigin::signed(bob), post_url.clone()));
+
+        // Unpausing a scope leaves the others paused
+        assert_ok!(Bullposting::unpause(RuntimeOrigin::root(), crate::PauseScope::Ending));
+        System::assert_last_event(Event::Unpaused { scope: crate::PauseScope::Ending }.into());
+        assert_eq!(crate::PalletStatus::<Test>::get(), paused(true, true, false));
+        assert_ok!(Bullposting::try_end_post(RuntimeOrigin::signed(bob), post_url.clone()));
+        assert_ok!(Bullposting::try_resolve_voting(RuntimeOrigin::signed(bob), post_url));
+        assert_noop!(Bullposting::try_submit_post(RuntimeOrigin::signed(bob), other_url.clone(), bond), Error::<Test>::Paused);
+
+        assert_ok!(Bullposting::unpause(RuntimeOrigin::root(), crate::PauseScope::All));
+        assert_eq!(crate::PalletStatus::<Test>::get(), paused(false, false, false));
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(bob), other_url, bond));
+    });
+}
+
+#[test]
+fn test_offchain_worker_respects_pause() {
+    let mut ext = new_test_ext();
+    let (offchain, _offchain_state) = testing::TestOffchainExt::new();
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        let post_url: Vec<u8> = "https://example.com/paused".into();
+        let post_id = sp_io::hashing::blake2_256(&post_url);
+        let end_call = crate::Call::<Test>::auto_end_post { id: post_id };
+        let period = 1000 + 100;
+
+        System::set_block_number(1);
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(0), post_url, 200));
+
+        // Posts that can be ended are left alone while ending is paused
+        System::set_block_number(period + 1);
+        assert_ok!(Bullposting::pause(RuntimeOrigin::root(), crate::PauseScope::Ending));
+        Bullposting::offchain_worker(period + 1);
+        assert!(pool_state.read().transactions.is_empty());
+        assert_eq!(
+            Bullposting::validate_unsigned(TransactionSource::External, &end_call),
+            InvalidTransaction::Call.into()
+        );
+
+        // And ended once it is unpaused
+        assert_ok!(Bullposting::unpause(RuntimeOrigin::root(), crate::PauseScope::Ending));
+        Bullposting::offchain_worker(period + 1);
+        assert_eq!(pool_state.read().transactions.len(), 1);
+        assert!(Bullposting::validate_unsigned(TransactionSource::External, &end_call).is_ok());
+    });
+}
+
+#[test]
+fn test_try_submit_post_indexes_url() {
+    let mut ext = new_test_ext();
+    let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+    let post_id = sp_io::hashing::blake2_256(&post_url);
+
+    ext.execute_with(|| {
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(0), post_url.clone(), 200));
+    });
+    ext.persist_offchain_overlay();
+
+    // Only the hash is in runtime state, the URL itself is in the offchain database under the post ID
+    assert_eq!(ext.offchain_db().get(&crate::url_key(&post_id)), Some(post_url.encode()));
+    assert_eq!(ext.offchain_db().get(&crate::url_key(&[0; 32])), None);
+}
+
+#[test]
+fn test_call_weights_follow_url_length() {
+    new_test_ext().execute_with(|| {
+        let short_url: Vec<u8> = "https://x.io".into();
+        let long_url: Vec<u8> = [b'x'; 2000].to_vec();
+        let weight = |call: crate::Call<Test>| call.get_dispatch_info().call_weight.ref_time();
+
+        // Each call is charged for hashing the URL it was actually given
+        assert!(
+            weight(crate::Call::try_submit_post { post_url: short_url.clone(), bond: 200 }) <
+            weight(crate::Call::try_submit_post { post_url: long_url.clone(), bond: 200 })
+        );
+        assert!(
+            weight(crate::Call::try_submit_vote { post_url: short_url.clone(), vote_amount: 200, direction: crate::Direction::Bullish }) <
+            weight(crate::Call::try_submit_vote { post_url: long_url.clone(), vote_amount: 200, direction: crate::Direction::Bullish })
+        );
+        assert!(
+            weight(crate::Call::try_update_vote { post_url: short_url.clone(), new_vote: 200, direction: crate::Direction::Bullish }) <
+            weight(crate::Call::try_update_vote { post_url: long_url.clone(), new_vote: 200, direction: crate::Direction::Bullish })
+        );
+        assert!(
+            weight(crate::Call::try_end_post { post_url: short_url.clone() }) <
+            weight(crate::Call::try_end_post { post_url: long_url.clone() })
+        );
+        assert!(
+            weight(crate::Call::try_submit_votes { votes: vec![(short_url.clone(), 200, crate::Direction::Bullish); 2] }) <
+            weight(crate::Call::try_submit_votes { votes: vec![(long_url.clone(), 200, crate::Direction::Bullish); 2] })
+        );
+        assert!(
+            weight(crate::Call::try_end_posts { post_urls: vec![short_url.clone(); 2] }) <
+            weight(crate::Call::try_end_posts { post_urls: vec![long_url.clone(); 2] })
+        );
+
+        // Ending a post by ID costs no more than ending it by its shortest possible URL
+        assert!(weight(crate::Call::auto_end_post { id: [0; 32] }) <= weight(crate::Call::try_end_post { post_url: "x".into() }));
+    });
+}
+
+#[test]
+fn test_genesis_posts_index_urls() {
+    let post_url: Vec<u8> = "https://example.com/genesis".into();
+    let post_id = sp_io::hashing::blake2_256(&post_url);
+    let genesis = crate::GenesisConfig::<Test> {
+        posts: vec![(0, post_url.clone(), 200, 10)],
+        ..Default::default()
+    };
+    let mut ext = new_test_ext_with_genesis(vec![(0, 1001)], genesis);
+
+    // The URL waits in runtime state until the first block indexes it like a submitted post's
+    ext.execute_with(|| {
+        assert!(crate::GenesisUrls::<Test>::contains_key(post_id));
+        Bullposting::on_initialize(1);
+        assert!(!crate::GenesisUrls::<Test>::contains_key(post_id));
+    });
+    ext.persist_offchain_overlay();
+
+    assert_eq!(ext.offchain_db().get(&crate::url_key(&post_id)), Some(post_url.encode()));
+}
+
+#[test]
+fn test_offchain_worker_attests_urls() {
+    let mut ext = new_test_ext();
+    // Shares the externalities' offchain database, so the worker can read the URLs indexed by `try_submit_post`
+    let (offchain, offchain_state) = testing::TestOffchainExt::with_offchain_db(ext.offchain_db());
+    let (pool, pool_state) = testing::TestTransactionPoolExt::new();
+    ext.register_extension(OffchainWorkerExt::new(offchain.clone()));
+    ext.register_extension(OffchainDbExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    let alice = 0;
+    let bond = 200;
+    let period = 1000 + 100;
+    let post_url: Vec<u8> = "https://paritytech.github.io/polkadot-sdk/master/sp_test_primitives/type.BlockNumber.html".into();
+    let post_id = sp_io::hashing::blake2_256(&post_url);
+    let page: Vec<u8> = "<html><head><TITLE>  Bullposting: the best chain </TITLE></head><body>Bullish</body></html>".into();
+    // The title is trimmed and truncated to MaxTitleLength
+    let title: BoundedVec<u8, MaxTitleLength> = BoundedVec::truncate_from("Bullposting: the".into());
+    // The offchain worker signs with the test key of the attester account 7
+    let attester = 7;
+    let attestation = |public: u64, status: u16, content_hash: [u8; 32]| crate::AttestationPayload::<Test> {
+        id: post_id,
+        status,
+        content_hash,
+        title: title.clone(),
+        public: UintAuthorityId(public),
+    };
+    let sign = |attestation: &crate::AttestationPayload<Test>| TestSignature(attestation.public.0, attestation.encode());
+    let payload = attestation(attester, 200, sp_io::hashing::blake2_256(&page));
+    let attest_call = crate::Call::<Test>::attest_url { attestation: payload.clone(), signature: sign(&payload) };
+    // The calls the offchain worker has submitted to the transaction pool so far
+    let submitted = || -> Vec<RuntimeCall> {
+        pool_state.read().transactions.iter()
+            .map(|tx| Extrinsic::decode(&mut &tx[..]).unwrap().function)
+            .collect()
+    };
+
+    ext.execute_with(|| {
+        AttestUrls::set(true);
+
+        // Go past genesis block so events get deposited
+        System::set_block_number(1);
+
+        assert_ok!(Bullposting::try_submit_post(RuntimeOrigin::signed(alice), post_url.clone(), bond));
+
+        // Nothing is fetched without the key of an attester, as the test HTTP client would panic
+        UintAuthorityId::set_all_keys(vec![attester]);
+        Bullposting::offchain_worker(1);
+        assert_noop!(
+            Bullposting::set_attesters(RuntimeOrigin::signed(alice), BoundedVec::truncate_from(vec![attester])),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(Bullposting::set_attesters(RuntimeOrigin::root(), BoundedVec::truncate_from(vec![attester])));
+        System::assert_last_event(Event::AttestersSet { attesters: BoundedVec::truncate_from(vec![attester]) }.into());
+    });
+    ext.persist_offchain_overlay();
+
+    // The test HTTP client answers the fetch with the page, and panics on any other request
+    offchain_state.write().expect_request(testing::PendingRequest {
+        method: "GET".into(),
+        uri: String::from_utf8(post_url.clone()).unwrap(),
+        response: Some(page.clone()),
+        sent: true,
+        ..Default::default()
+    });
+
+    ext.execute_with(|| {
+        // The URL is fetched once, however many times the offchain worker runs
+        System::set_block_number(2);
+        Bullposting::offchain_worker(2);
+        Bullposting::offchain_worker(2);
+        assert_eq!(submitted(), vec![attest_call.clone().into()]);
+
+        // Signed attestations are accepted from any source and gossiped, as the attester need not be a block author
+        for source in [TransactionSource::Local, TransactionSource::External, TransactionSource::InBlock] {
+            assert!(Bullposting::validate_unsigned(source, &attest_call).unwrap().propagate);
+        }
+
+        // Attestations are not accepted, even in blocks, without the signature of an attester
+        let outsider = attestation(8, 200, [0; 32]);
+        assert_eq!(
+            Bullposting::validate_unsigned(
+                TransactionSource::InBlock,
+                &crate::Call::<Test>::attest_url { attestation: outsider.clone(), signature: sign(&outsider) },
+            ),
+            InvalidTransaction::BadSigner.into()
+        );
+        assert_eq!(
+            Bullposting::validate_unsigned(
+                TransactionSource::InBlock,
+                &crate::Call::<Test>::attest_url { attestation: attestation(attester, 404, [0; 32]), signature: sign(&payload) },
+            ),
+            InvalidTransaction::BadProof.into()
+        );
+
+        assert_noop!(
+            Bullposting::attest_url(RuntimeOrigin::signed(alice), payload.clone(), sign(&payload)),
+            frame_support::error::BadOrigin
+        );
+        assert_ok!(Bullposting::attest_url(RuntimeOrigin::none(), payload.clone(), sign(&payload)));
+        System::assert_last_event(Event::UrlAttested { id: post_id, status: 200, content_hash: sp_io::hashing::blake2_256(&page) }.into());
+        assert_eq!(
+            crate::Attestations::<Test>::get(post_id),
+            Some(crate::Attestation { status: 200, content_hash: sp_io::hashing::blake2_256(&page), title: title.clone(), attested_at: 2 })
+        );
+
+        // Each post is only attested once
+        let second = attestation(attester, 404, [0; 32]);
+        assert_noop!(
+            Bullposting::attest_url(RuntimeOrigin::none(), second.clone(), sign(&second)),
+            Error::<Test>::AlreadyAttested
+        );
+        assert_eq!(
+            Bullposting::validate_unsigned(TransactionSource::Local, &attest_call),
+            InvalidTransaction::Stale.into()
+        );
+
+        // Without AttestUrls, attestations are not accepted at all
+        AttestUrls::set(false);
+        assert_eq!(
+            Bullposting::validate_unsigned(TransactionSource::InBlock, &attest_call),
+            InvalidTransaction::Call.into()
+        );
+
+        // The attestation is removed with the post
+        System::set_block_number(period + 1);
+        assert_ok!(Bullposting::auto_end_post(RuntimeOrigin::none(), post_id));
+        assert_ok!(Bullposting::auto_resolve_voting(RuntimeOrigin::none(), post_id));
+        assert_eq!(crate::Attestations::<Test>::get(post_id), None);
+    });
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        (any::<u8>(), any::<u8>(), any::<u16>())
+            .prop_map(|(who, url, bond)| Action::SubmitPost { who, url, bond }),
+        (any::<u8>(), any::<u8>(), any::<u16>(), any::<u8>())
+            .prop_map(|(who, url, amount, direction)| Action::SubmitVote { who, url, amount, direction }),
+        (any::<u8>(), any::<u8>(), any::<u16>(), any::<u8>())
+            .prop_map(|(who, url, amount, direction)| Action::UpdateVote { who, url, amount, direction }),
+        any::<u16>().prop_map(|blocks| Action::AdvanceBlocks { blocks }),
+        (any::<u8>(), any::<u8>()).prop_map(|(who, url)| Action::EndPost { who, url }),
+        (any::<u8>(), any::<u8>()).prop_map(|(who, url)| Action::ResolveVoting { who, url }),
+        (any::<u8>(), any::<u8>()).prop_map(|(who, url)| Action::ReportPost { who, url }),
+        (any::<u8>(), any::<bool>()).prop_map(|(url, upheld)| Action::ForceRemovePost { url, upheld }),
+        (any::<u8>(), any::<u8>(), any::<u16>()).prop_map(|(who, url, bond)| Action::AppealPost { who, url, bond }),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn test_random_call_sequences(actions in prop::collection::vec(action(), 1..100)) {
+        harness::run(&actions);
+    }
+
+    #[test]
+    fn test_random_call_sequences_parimutuel(actions in prop::collection::vec(action(), 1..100)) {
+        Parimutuel::set(true);
+        harness::run(&actions);
+    }
+
+    #[test]
+    fn test_random_call_sequences_accuracy_weighted(actions in prop::collection::vec(action(), 1..100)) {
+        AccuracyWeight::set(sp_runtime::Percent::from_percent(50));
+        harness::run(&actions);
+    }
+}
@@ -1,31 +1,23 @@
 
-//! Autogenerated weights for `pallet_bullposting`
+//! Estimated weights for `pallet_bullposting`
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 32.0.0
-//! DATE: 2025-02-27, STEPS: `10`, REPEAT: `2`, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! WORST CASE MAP SIZE: `1000000`
-//! HOSTNAME: `BeefSlab`, CPU: `12th Gen Intel(R) Core(TM) i7-12700H`
-//! WASM-EXECUTION: `Compiled`, CHAIN: `None`, DB CACHE: `1024`
-
-// Executed Command:
-// frame-omni-bencher
-// v1
-// benchmark
-// pallet
-// --runtime
-// target/release/wbuild/solochain-bullposting-runtime/solochain_bullposting_runtime.compact.compressed.wasm
-// --pallet
-// pallet_bullposting
-// --extrinsic
-// *
-// --steps
-// 10
-// --repeat
-// 2
-// --template
-// ./pallets/benchmarking/frame-weight-template.hbs
-// --output
-// ./pallets/bullposting/src/weights.rs
+//! These weights were not generated by a benchmark run of the current benchmarks. The weights of
+//! `try_submit_post`, `try_submit_vote`, `try_update_vote`, `try_end_post` and `try_resolve_voting`
+//! started from a frame-omni-bencher run on 2025-02-27 (STEPS: `10`, REPEAT: `2`, CPU: `12th Gen
+//! Intel(R) Core(TM) i7-12700H`) and have since been adjusted by hand for the storage those calls
+//! gained. The weights of every other call, hook and migration step are estimates derived from
+//! comparable calls. The execution times, storage accesses and proof sizes in the comments below
+//! are estimates in the same way.
+//!
+//! Regenerate this file from the current benchmarks before relying on these weights on a live chain:
+//!
+//! ```sh
+//! frame-omni-bencher v1 benchmark pallet \
+//!     --runtime target/release/wbuild/solochain-bullposting-runtime/solochain_bullposting_runtime.compact.compressed.wasm \
+//!     --pallet pallet_bullposting --extrinsic "*" --steps 10 --repeat 2 \
+//!     --template ./pallets/benchmarking/frame-weight-template.hbs \
+//!     --output ./pallets/bullposting/src/weights.rs
+//! ```
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
 #![allow(unused_parens)]
@@ -37,31 +29,56 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_bullposting`.
 pub trait WeightInfo {
-	fn try_submit_post() -> Weight;
-	fn try_submit_vote() -> Weight;
-	fn try_update_vote() -> Weight;
-	fn try_end_post() -> Weight;
-	fn try_resolve_voting(x: u32, ) -> Weight;
+	fn try_submit_post(u: u32, ) -> Weight;
+	fn try_submit_vote(u: u32, ) -> Weight;
+	fn try_update_vote(u: u32, v: u32, ) -> Weight;
+	fn try_end_post(u: u32, ) -> Weight;
+	fn try_resolve_voting(x: u32, v: u32, ) -> Weight;
+	fn report_post() -> Weight;
+	fn force_remove_post() -> Weight;
+	fn appeal_post() -> Weight;
+	fn try_submit_votes(x: u32, u: u32, ) -> Weight;
+	fn try_end_posts(x: u32, u: u32, ) -> Weight;
+	fn attest_url() -> Weight;
+	fn pause() -> Weight;
+	fn unpause() -> Weight;
+	fn set_attesters(a: u32, ) -> Weight;
+	fn prune_outcomes(x: u32, ) -> Weight;
+	fn prune_voter_records(x: u32, ) -> Weight;
+	fn migrate_v0_to_v1_step() -> Weight;
+	fn migrate_v1_to_v2_step(x: u32, ) -> Weight;
+	fn migrate_v2_to_v3_step() -> Weight;
+	fn migrate_v3_to_v4_step() -> Weight;
+	fn migrate_v4_to_v5_step() -> Weight;
 }
 
-/// Weights for `pallet_bullposting` using the Substrate node and recommended hardware.
+/// Estimated weights for `pallet_bullposting`, see the module documentation.
 pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Posts` (r:1 w:1)
-	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
-	fn try_submit_post() -> Weight {
+	/// Storage: `Bullposting::Submissions` (r:1 w:1)
+	/// Proof: `Bullposting::Submissions` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 2000]`.
+	fn try_submit_post(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4`
-		//  Estimated: `3598`
-		// Minimum execution time: 61_997_000 picoseconds.
-		Weight::from_parts(66_797_000, 3598)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
-			.saturating_add(T::DbWeight::get().writes(2_u64))
+		//  Estimated: `3689`
+		// Minimum execution time: 63_871_000 picoseconds.
+		Weight::from_parts(64_907_000, 3689)
+			// Standard Error: 312
+			.saturating_add(Weight::from_parts(2_104, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Posts` (r:1 w:1)
-	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::VoteCounts` (r:1 w:1)
 	/// Proof: `Bullposting::VoteCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Votes` (r:1 w:1)
@@ -72,19 +89,30 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Bullposting::Voters` (r:1 w:1)
-	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: Some(320050), added: 322525, mode: `MaxEncodedLen`)
-	fn try_submit_vote() -> Weight {
+	/// Storage: `Bullposting::Voters` (r:0 w:1)
+	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecords` (r:1 w:0)
+	/// Proof: `Bullposting::VoterRecords` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteWeights` (r:0 w:1)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteAmounts` (r:1 w:1)
+	/// Proof: `Bullposting::VoteAmounts` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 2000]`.
+	fn try_submit_vote(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `268`
-		//  Estimated: `323515`
-		// Minimum execution time: 45_096_000 picoseconds.
-		Weight::from_parts(51_150_000, 323515)
-			.saturating_add(T::DbWeight::get().reads(7_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+		//  Estimated: `4764`
+		// Minimum execution time: 41_870_000 picoseconds.
+		Weight::from_parts(46_976_000, 4764)
+			// Standard Error: 287
+			.saturating_add(Weight::from_parts(2_087, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
 	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Posts` (r:1 w:1)
-	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Votes` (r:1 w:1)
 	/// Proof: `Bullposting::Votes` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -93,34 +121,62 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	fn try_update_vote() -> Weight {
+	/// Storage: `Bullposting::VoteWeights` (r:1 w:1)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecords` (r:1 w:0)
+	/// Proof: `Bullposting::VoterRecords` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteAmounts` (r:1 w:1)
+	/// Proof: `Bullposting::VoteAmounts` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 2000]`.
+	/// The range of component `v` is `[1, 100]`.
+	fn try_update_vote(u: u32, v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `526`
-		//  Estimated: `4764`
-		// Minimum execution time: 42_049_000 picoseconds.
-		Weight::from_parts(45_730_000, 4764)
-			.saturating_add(T::DbWeight::get().reads(5_u64))
-			.saturating_add(T::DbWeight::get().writes(4_u64))
+		//  Measured:  `510 + v * (16 ±0)`
+		//  Estimated: `4764 + v * (16 ±0)`
+		// Minimum execution time: 38_912_000 picoseconds.
+		Weight::from_parts(41_548_000, 4764)
+			// Standard Error: 301
+			.saturating_add(Weight::from_parts(2_091, 0).saturating_mul(u.into()))
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(24_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 16).saturating_mul(v.into()))
 	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Posts` (r:1 w:1)
-	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:2 w:2)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
-	fn try_end_post() -> Weight {
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::PostOutcomes` (r:0 w:1)
+	/// Proof: `Bullposting::PostOutcomes` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::OutcomeExpiries` (r:0 w:1)
+	/// Proof: `Bullposting::OutcomeExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteWeights` (r:1 w:0)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 2000]`.
+	fn try_end_post(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `444`
-		//  Estimated: `3598`
-		// Minimum execution time: 61_337_000 picoseconds.
-		Weight::from_parts(62_462_000, 3598)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+		//  Measured:  `743`
+		//  Estimated: `8799`
+		// Minimum execution time: 97_330_000 picoseconds.
+		Weight::from_parts(100_646_000, 8799)
+			// Standard Error: 455
+			.saturating_add(Weight::from_parts(2_112, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Posts` (r:1 w:0)
-	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	/// Storage: `Bullposting::Voters` (r:1 w:1)
-	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: Some(320050), added: 322525, mode: `MaxEncodedLen`)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Voters` (r:1001 w:1000)
+	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Votes` (r:1000 w:1000)
 	/// Proof: `Bullposting::Votes` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1000 w:1000)
@@ -133,38 +189,354 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Bullposting::VoteCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Removals` (r:1 w:1)
+	/// Proof: `Bullposting::Removals` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Reports` (r:1 w:0)
+	/// Proof: `Bullposting::Reports` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecords` (r:1000 w:1000)
+	/// Proof: `Bullposting::VoterRecords` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecordExpiries` (r:0 w:1000)
+	/// Proof: `Bullposting::VoterRecordExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteWeights` (r:0 w:1000)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteAmounts` (r:1000 w:1000)
+	/// Proof: `Bullposting::VoteAmounts` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 10000]`.
-	fn try_resolve_voting(x: u32, ) -> Weight {
+	/// The range of component `v` is `[1, 100]`.
+	fn try_resolve_voting(x: u32, v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `322670 + x * (84 ±0)`
-		//  Estimated: `2472363 + x * (185 ±71)`
+		//  Measured:  `129 + x * (180 ±0) + v * (16000 ±0)`
+		//  Estimated: `2149838 + x * (2756 ±71) + v * (16000 ±0)`
 		// Minimum execution time: 75_462_000 picoseconds.
-		Weight::from_parts(20_200_538_953, 2472363)
+		Weight::from_parts(20_200_538_953, 2149838)
 			// Standard Error: 590_373
 			.saturating_add(Weight::from_parts(2_108_637, 0).saturating_mul(x.into()))
-			.saturating_add(T::DbWeight::get().reads(2623_u64))
-			.saturating_add(T::DbWeight::get().writes(1968_u64))
-			.saturating_add(Weight::from_parts(0, 185).saturating_mul(x.into()))
+			// Standard Error: 1_203_512
+			.saturating_add(Weight::from_parts(48_000_000, 0).saturating_mul(v.into()))
+			.saturating_add(T::DbWeight::get().reads(2627_u64))
+			.saturating_add(T::DbWeight::get().writes(1969_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes(5_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 6881).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 16000).saturating_mul(v.into()))
+	}
+	/// Storage: `Bullposting::Posts` (r:1 w:0)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Removals` (r:1 w:0)
+	/// Proof: `Bullposting::Removals` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Reports` (r:1 w:1)
+	/// Proof: `Bullposting::Reports` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn report_post() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3689`
+		// Minimum execution time: 38_904_000 picoseconds.
+		Weight::from_parts(40_218_000, 3689)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bullposting::Posts` (r:1 w:1)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Removals` (r:1 w:1)
+	/// Proof: `Bullposting::Removals` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::PostOutcomes` (r:0 w:1)
+	/// Proof: `Bullposting::PostOutcomes` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::OutcomeExpiries` (r:0 w:1)
+	/// Proof: `Bullposting::OutcomeExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn force_remove_post() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `480`
+		//  Estimated: `3689`
+		// Minimum execution time: 55_120_000 picoseconds.
+		Weight::from_parts(57_391_000, 3689)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:1 w:1)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn appeal_post() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3689`
+		// Minimum execution time: 44_806_000 picoseconds.
+		Weight::from_parts(46_129_000, 3689)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:50 w:50)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteCounts` (r:50 w:50)
+	/// Proof: `Bullposting::VoteCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Votes` (r:50 w:50)
+	/// Proof: `Bullposting::Votes` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Voters` (r:0 w:50)
+	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecords` (r:1 w:0)
+	/// Proof: `Bullposting::VoterRecords` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteWeights` (r:0 w:50)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteAmounts` (r:50 w:50)
+	/// Proof: `Bullposting::VoteAmounts` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	/// The range of component `u` is `[1, 100000]`.
+	fn try_submit_votes(x: u32, u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `170 + x * (98 ±0)`
+		//  Estimated: `4764 + x * (2699 ±0)`
+		// Minimum execution time: 47_313_000 picoseconds.
+		Weight::from_parts(21_604_000, 4764)
+			// Standard Error: 41_275
+			.saturating_add(Weight::from_parts(23_173_000, 0).saturating_mul(x.into()))
+			// Standard Error: 312
+			.saturating_add(Weight::from_parts(2_094, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes(6_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2699).saturating_mul(x.into()))
+	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:50 w:50)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:51 w:51)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:51 w:51)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::PostOutcomes` (r:0 w:1)
+	/// Proof: `Bullposting::PostOutcomes` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::OutcomeExpiries` (r:0 w:1)
+	/// Proof: `Bullposting::OutcomeExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteWeights` (r:50 w:0)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	/// The range of component `u` is `[1, 100000]`.
+	fn try_end_posts(x: u32, u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215 + x * (425 ±0)`
+		//  Estimated: `3593 + x * (2699 ±0)`
+		// Minimum execution time: 90_148_000 picoseconds.
+		Weight::from_parts(14_276_000, 3593)
+			// Standard Error: 63_912
+			.saturating_add(Weight::from_parts(75_301_000, 0).saturating_mul(x.into()))
+			// Standard Error: 312
+			.saturating_add(Weight::from_parts(2_108, 0).saturating_mul(u.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes(5_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2699).saturating_mul(x.into()))
+	}
+	/// Storage: `Bullposting::Attesters` (r:1 w:0)
+	/// Proof: `Bullposting::Attesters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:1 w:0)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Attestations` (r:1 w:1)
+	/// Proof: `Bullposting::Attestations` (`max_values`: None, `max_size`: Some(344), added: 2819, mode: `MaxEncodedLen`)
+	fn attest_url() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247`
+		//  Estimated: `3809`
+		// Minimum execution time: 61_844_000 picoseconds.
+		Weight::from_parts(63_217_000, 3809)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:1)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1488`
+		// Minimum execution time: 7_914_000 picoseconds.
+		Weight::from_parts(8_302_000, 1488)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:1)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
+	fn unpause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1488`
+		// Minimum execution time: 7_856_000 picoseconds.
+		Weight::from_parts(8_197_000, 1488)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bullposting::Attesters` (r:0 w:1)
+	/// Proof: `Bullposting::Attesters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 16]`.
+	fn set_attesters(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_218_000 picoseconds.
+		Weight::from_parts(5_612_000, 0)
+			// Standard Error: 1_047
+			.saturating_add(Weight::from_parts(21_389, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bullposting::OutcomeExpiries` (r:1001 w:1000)
+	/// Proof: `Bullposting::OutcomeExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::PostOutcomes` (r:1000 w:1000)
+	/// Proof: `Bullposting::PostOutcomes` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 1000]`.
+	fn prune_outcomes(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94 + x * (201 ±0)`
+		//  Estimated: `3525 + x * (2624 ±0)`
+		// Minimum execution time: 3_412_000 picoseconds.
+		Weight::from_parts(3_689_000, 3525)
+			// Standard Error: 3_861
+			.saturating_add(Weight::from_parts(9_874_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes(2_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2624).saturating_mul(x.into()))
+	}
+	/// Storage: `Bullposting::VoterRecordExpiries` (r:1001 w:1000)
+	/// Proof: `Bullposting::VoterRecordExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecords` (r:1000 w:1000)
+	/// Proof: `Bullposting::VoterRecords` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 1000]`.
+	fn prune_voter_records(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94 + x * (149 ±0)`
+		//  Estimated: `3525 + x * (2539 ±0)`
+		// Minimum execution time: 3_398_000 picoseconds.
+		Weight::from_parts(3_702_000, 3525)
+			// Standard Error: 3_514
+			.saturating_add(Weight::from_parts(9_512_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads(2_u64).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().writes(2_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(x.into()))
+	}
+	/// Storage: `Bullposting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `Bullposting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:2 w:1)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	fn migrate_v0_to_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229`
+		//  Estimated: `6374`
+		// Minimum execution time: 16_940_000 picoseconds.
+		Weight::from_parts(17_512_000, 6374)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bullposting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `Bullposting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Voters` (r:2 w:10001)
+	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[0, 10000]`.
+	fn migrate_v1_to_v2_step(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141 + x * (32 ±0)`
+		//  Estimated: `3606 + x * (32 ±0)`
+		// Minimum execution time: 14_218_000 picoseconds.
+		Weight::from_parts(14_703_000, 3606)
+			// Standard Error: 1_294
+			.saturating_add(Weight::from_parts(3_941_000, 0).saturating_mul(x.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(x.into()))
+	}
+	/// Storage: `Bullposting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `Bullposting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:2 w:1)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	fn migrate_v2_to_v3_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `243`
+		//  Estimated: `6388`
+		// Minimum execution time: 17_164_000 picoseconds.
+		Weight::from_parts(17_792_000, 6388)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bullposting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `Bullposting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:2 w:0)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Submissions` (r:1 w:1)
+	/// Proof: `Bullposting::Submissions` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn migrate_v3_to_v4_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `259`
+		//  Estimated: `6388`
+		// Minimum execution time: 18_327_000 picoseconds.
+		Weight::from_parts(19_054_000, 6388)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bullposting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `Bullposting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Votes` (r:2 w:0)
+	/// Proof: `Bullposting::Votes` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteAmounts` (r:1 w:1)
+	/// Proof: `Bullposting::VoteAmounts` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	fn migrate_v4_to_v5_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `282`
+		//  Estimated: `6134`
+		// Minimum execution time: 18_904_000 picoseconds.
+		Weight::from_parts(19_611_000, 6134)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Posts` (r:1 w:1)
-	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
-	fn try_submit_post() -> Weight {
+	/// Storage: `Bullposting::Submissions` (r:1 w:1)
+	/// Proof: `Bullposting::Submissions` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 2000]`.
+	fn try_submit_post(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4`
-		//  Estimated: `3598`
-		// Minimum execution time: 61_997_000 picoseconds.
-		Weight::from_parts(66_797_000, 3598)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
-			.saturating_add(RocksDbWeight::get().writes(2_u64))
+		//  Estimated: `3689`
+		// Minimum execution time: 63_871_000 picoseconds.
+		Weight::from_parts(64_907_000, 3689)
+			// Standard Error: 312
+			.saturating_add(Weight::from_parts(2_104, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
 	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Posts` (r:1 w:1)
-	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::VoteCounts` (r:1 w:1)
 	/// Proof: `Bullposting::VoteCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Votes` (r:1 w:1)
@@ -175,19 +547,30 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	/// Storage: `Bullposting::Voters` (r:1 w:1)
-	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: Some(320050), added: 322525, mode: `MaxEncodedLen`)
-	fn try_submit_vote() -> Weight {
+	/// Storage: `Bullposting::Voters` (r:0 w:1)
+	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecords` (r:1 w:0)
+	/// Proof: `Bullposting::VoterRecords` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteWeights` (r:0 w:1)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteAmounts` (r:1 w:1)
+	/// Proof: `Bullposting::VoteAmounts` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 2000]`.
+	fn try_submit_vote(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `268`
-		//  Estimated: `323515`
-		// Minimum execution time: 45_096_000 picoseconds.
-		Weight::from_parts(51_150_000, 323515)
-			.saturating_add(RocksDbWeight::get().reads(7_u64))
-			.saturating_add(RocksDbWeight::get().writes(6_u64))
+		//  Estimated: `4764`
+		// Minimum execution time: 41_870_000 picoseconds.
+		Weight::from_parts(46_976_000, 4764)
+			// Standard Error: 287
+			.saturating_add(Weight::from_parts(2_087, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
 	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Posts` (r:1 w:1)
-	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Votes` (r:1 w:1)
 	/// Proof: `Bullposting::Votes` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -196,34 +579,62 @@ impl WeightInfo for () {
 	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Locks` (r:1 w:0)
 	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
-	fn try_update_vote() -> Weight {
+	/// Storage: `Bullposting::VoteWeights` (r:1 w:1)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecords` (r:1 w:0)
+	/// Proof: `Bullposting::VoterRecords` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteAmounts` (r:1 w:1)
+	/// Proof: `Bullposting::VoteAmounts` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 2000]`.
+	/// The range of component `v` is `[1, 100]`.
+	fn try_update_vote(u: u32, v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `526`
-		//  Estimated: `4764`
-		// Minimum execution time: 42_049_000 picoseconds.
-		Weight::from_parts(45_730_000, 4764)
-			.saturating_add(RocksDbWeight::get().reads(5_u64))
-			.saturating_add(RocksDbWeight::get().writes(4_u64))
+		//  Measured:  `510 + v * (16 ±0)`
+		//  Estimated: `4764 + v * (16 ±0)`
+		// Minimum execution time: 38_912_000 picoseconds.
+		Weight::from_parts(41_548_000, 4764)
+			// Standard Error: 301
+			.saturating_add(Weight::from_parts(2_091, 0).saturating_mul(u.into()))
+			// Standard Error: 1_204
+			.saturating_add(Weight::from_parts(24_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(Weight::from_parts(0, 16).saturating_mul(v.into()))
 	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Posts` (r:1 w:1)
-	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:3 w:3)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Storage: `Balances::Holds` (r:2 w:2)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
-	fn try_end_post() -> Weight {
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::PostOutcomes` (r:0 w:1)
+	/// Proof: `Bullposting::PostOutcomes` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::OutcomeExpiries` (r:0 w:1)
+	/// Proof: `Bullposting::OutcomeExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteWeights` (r:1 w:0)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// The range of component `u` is `[1, 2000]`.
+	fn try_end_post(u: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `444`
-		//  Estimated: `3598`
-		// Minimum execution time: 61_337_000 picoseconds.
-		Weight::from_parts(62_462_000, 3598)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+		//  Measured:  `743`
+		//  Estimated: `8799`
+		// Minimum execution time: 97_330_000 picoseconds.
+		Weight::from_parts(100_646_000, 8799)
+			// Standard Error: 455
+			.saturating_add(Weight::from_parts(2_112, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Posts` (r:1 w:0)
-	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(133), added: 2608, mode: `MaxEncodedLen`)
-	/// Storage: `Bullposting::Voters` (r:1 w:1)
-	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: Some(320050), added: 322525, mode: `MaxEncodedLen`)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Voters` (r:1001 w:1000)
+	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
 	/// Storage: `Bullposting::Votes` (r:1000 w:1000)
 	/// Proof: `Bullposting::Votes` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Freezes` (r:1000 w:1000)
@@ -236,17 +647,324 @@ impl WeightInfo for () {
 	/// Proof: `Bullposting::VoteCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Balances::Holds` (r:1 w:1)
 	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Removals` (r:1 w:1)
+	/// Proof: `Bullposting::Removals` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Reports` (r:1 w:0)
+	/// Proof: `Bullposting::Reports` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecords` (r:1000 w:1000)
+	/// Proof: `Bullposting::VoterRecords` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecordExpiries` (r:0 w:1000)
+	/// Proof: `Bullposting::VoterRecordExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteWeights` (r:0 w:1000)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteAmounts` (r:1000 w:1000)
+	/// Proof: `Bullposting::VoteAmounts` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
 	/// The range of component `x` is `[1, 10000]`.
-	fn try_resolve_voting(x: u32, ) -> Weight {
+	/// The range of component `v` is `[1, 100]`.
+	fn try_resolve_voting(x: u32, v: u32, ) -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `322670 + x * (84 ±0)`
-		//  Estimated: `2472363 + x * (185 ±71)`
+		//  Measured:  `129 + x * (180 ±0) + v * (16000 ±0)`
+		//  Estimated: `2149838 + x * (2756 ±71) + v * (16000 ±0)`
 		// Minimum execution time: 75_462_000 picoseconds.
-		Weight::from_parts(20_200_538_953, 2472363)
+		Weight::from_parts(20_200_538_953, 2149838)
 			// Standard Error: 590_373
 			.saturating_add(Weight::from_parts(2_108_637, 0).saturating_mul(x.into()))
-			.saturating_add(RocksDbWeight::get().reads(2623_u64))
-			.saturating_add(RocksDbWeight::get().writes(1968_u64))
-			.saturating_add(Weight::from_parts(0, 185).saturating_mul(x.into()))
+			// Standard Error: 1_203_512
+			.saturating_add(Weight::from_parts(48_000_000, 0).saturating_mul(v.into()))
+			.saturating_add(RocksDbWeight::get().reads(2627_u64))
+			.saturating_add(RocksDbWeight::get().writes(1969_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().writes(5_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 6881).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 16000).saturating_mul(v.into()))
+	}
+	/// Storage: `Bullposting::Posts` (r:1 w:0)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Removals` (r:1 w:0)
+	/// Proof: `Bullposting::Removals` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Reports` (r:1 w:1)
+	/// Proof: `Bullposting::Reports` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn report_post() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `312`
+		//  Estimated: `3689`
+		// Minimum execution time: 38_904_000 picoseconds.
+		Weight::from_parts(40_218_000, 3689)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bullposting::Posts` (r:1 w:1)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Removals` (r:1 w:1)
+	/// Proof: `Bullposting::Removals` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::PostOutcomes` (r:0 w:1)
+	/// Proof: `Bullposting::PostOutcomes` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::OutcomeExpiries` (r:0 w:1)
+	/// Proof: `Bullposting::OutcomeExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn force_remove_post() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `480`
+		//  Estimated: `3689`
+		// Minimum execution time: 55_120_000 picoseconds.
+		Weight::from_parts(57_391_000, 3689)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:1 w:1)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn appeal_post() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3689`
+		// Minimum execution time: 44_806_000 picoseconds.
+		Weight::from_parts(46_129_000, 3689)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:50 w:50)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteCounts` (r:50 w:50)
+	/// Proof: `Bullposting::VoteCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Votes` (r:50 w:50)
+	/// Proof: `Bullposting::Votes` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(67), added: 2542, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Voters` (r:0 w:50)
+	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: Some(96), added: 2571, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecords` (r:1 w:0)
+	/// Proof: `Bullposting::VoterRecords` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteWeights` (r:0 w:50)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteAmounts` (r:50 w:50)
+	/// Proof: `Bullposting::VoteAmounts` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	/// The range of component `u` is `[1, 100000]`.
+	fn try_submit_votes(x: u32, u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `170 + x * (98 ±0)`
+		//  Estimated: `4764 + x * (2699 ±0)`
+		// Minimum execution time: 47_313_000 picoseconds.
+		Weight::from_parts(21_604_000, 4764)
+			// Standard Error: 41_275
+			.saturating_add(Weight::from_parts(23_173_000, 0).saturating_mul(x.into()))
+			// Standard Error: 312
+			.saturating_add(Weight::from_parts(2_094, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().writes(6_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2699).saturating_mul(x.into()))
+	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:0)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:50 w:50)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:51 w:51)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:51 w:51)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::TotalIssuance` (r:1 w:1)
+	/// Proof: `Balances::TotalIssuance` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::PostOutcomes` (r:0 w:1)
+	/// Proof: `Bullposting::PostOutcomes` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::OutcomeExpiries` (r:0 w:1)
+	/// Proof: `Bullposting::OutcomeExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteWeights` (r:50 w:0)
+	/// Proof: `Bullposting::VoteWeights` (`max_values`: None, `max_size`: Some(112), added: 2587, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[1, 50]`.
+	/// The range of component `u` is `[1, 100000]`.
+	fn try_end_posts(x: u32, u: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `215 + x * (425 ±0)`
+		//  Estimated: `3593 + x * (2699 ±0)`
+		// Minimum execution time: 90_148_000 picoseconds.
+		Weight::from_parts(14_276_000, 3593)
+			// Standard Error: 63_912
+			.saturating_add(Weight::from_parts(75_301_000, 0).saturating_mul(x.into()))
+			// Standard Error: 312
+			.saturating_add(Weight::from_parts(2_108, 0).saturating_mul(u.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().writes(5_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2699).saturating_mul(x.into()))
+	}
+	/// Storage: `Bullposting::Attesters` (r:1 w:0)
+	/// Proof: `Bullposting::Attesters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:1 w:0)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Attestations` (r:1 w:1)
+	/// Proof: `Bullposting::Attestations` (`max_values`: None, `max_size`: Some(344), added: 2819, mode: `MaxEncodedLen`)
+	fn attest_url() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `247`
+		//  Estimated: `3809`
+		// Minimum execution time: 61_844_000 picoseconds.
+		Weight::from_parts(63_217_000, 3809)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:1)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
+	fn pause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1488`
+		// Minimum execution time: 7_914_000 picoseconds.
+		Weight::from_parts(8_302_000, 1488)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bullposting::PalletStatus` (r:1 w:1)
+	/// Proof: `Bullposting::PalletStatus` (`max_values`: Some(1), `max_size`: Some(3), added: 498, mode: `MaxEncodedLen`)
+	fn unpause() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1488`
+		// Minimum execution time: 7_856_000 picoseconds.
+		Weight::from_parts(8_197_000, 1488)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bullposting::Attesters` (r:0 w:1)
+	/// Proof: `Bullposting::Attesters` (`max_values`: Some(1), `max_size`: Some(513), added: 1008, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[0, 16]`.
+	fn set_attesters(a: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 5_218_000 picoseconds.
+		Weight::from_parts(5_612_000, 0)
+			// Standard Error: 1_047
+			.saturating_add(Weight::from_parts(21_389, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Bullposting::OutcomeExpiries` (r:1001 w:1000)
+	/// Proof: `Bullposting::OutcomeExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::PostOutcomes` (r:1000 w:1000)
+	/// Proof: `Bullposting::PostOutcomes` (`max_values`: None, `max_size`: Some(149), added: 2624, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 1000]`.
+	fn prune_outcomes(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94 + x * (201 ±0)`
+		//  Estimated: `3525 + x * (2624 ±0)`
+		// Minimum execution time: 3_412_000 picoseconds.
+		Weight::from_parts(3_689_000, 3525)
+			// Standard Error: 3_861
+			.saturating_add(Weight::from_parts(9_874_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().writes(2_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2624).saturating_mul(x.into()))
+	}
+	/// Storage: `Bullposting::VoterRecordExpiries` (r:1001 w:1000)
+	/// Proof: `Bullposting::VoterRecordExpiries` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoterRecords` (r:1000 w:1000)
+	/// Proof: `Bullposting::VoterRecords` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `x` is `[0, 1000]`.
+	fn prune_voter_records(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `94 + x * (149 ±0)`
+		//  Estimated: `3525 + x * (2539 ±0)`
+		// Minimum execution time: 3_398_000 picoseconds.
+		Weight::from_parts(3_702_000, 3525)
+			// Standard Error: 3_514
+			.saturating_add(Weight::from_parts(9_512_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads(2_u64).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().writes(2_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(x.into()))
+	}
+	/// Storage: `Bullposting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `Bullposting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:2 w:1)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	fn migrate_v0_to_v1_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `229`
+		//  Estimated: `6374`
+		// Minimum execution time: 16_940_000 picoseconds.
+		Weight::from_parts(17_512_000, 6374)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bullposting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `Bullposting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Voters` (r:2 w:10001)
+	/// Proof: `Bullposting::Voters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `x` is `[0, 10000]`.
+	fn migrate_v1_to_v2_step(x: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `141 + x * (32 ±0)`
+		//  Estimated: `3606 + x * (32 ±0)`
+		// Minimum execution time: 14_218_000 picoseconds.
+		Weight::from_parts(14_703_000, 3606)
+			// Standard Error: 1_294
+			.saturating_add(Weight::from_parts(3_941_000, 0).saturating_mul(x.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64).saturating_mul(x.into()))
+			.saturating_add(Weight::from_parts(0, 32).saturating_mul(x.into()))
+	}
+	/// Storage: `Bullposting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `Bullposting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:2 w:1)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	fn migrate_v2_to_v3_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `243`
+		//  Estimated: `6388`
+		// Minimum execution time: 17_164_000 picoseconds.
+		Weight::from_parts(17_792_000, 6388)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bullposting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `Bullposting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Posts` (r:2 w:0)
+	/// Proof: `Bullposting::Posts` (`max_values`: None, `max_size`: Some(224), added: 2699, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Submissions` (r:1 w:1)
+	/// Proof: `Bullposting::Submissions` (`max_values`: None, `max_size`: Some(60), added: 2535, mode: `MaxEncodedLen`)
+	fn migrate_v3_to_v4_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `259`
+		//  Estimated: `6388`
+		// Minimum execution time: 18_327_000 picoseconds.
+		Weight::from_parts(19_054_000, 6388)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Bullposting:::__STORAGE_VERSION__:` (r:1 w:1)
+	/// Proof: `Bullposting:::__STORAGE_VERSION__:` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::Votes` (r:2 w:0)
+	/// Proof: `Bullposting::Votes` (`max_values`: None, `max_size`: Some(113), added: 2588, mode: `MaxEncodedLen`)
+	/// Storage: `Bullposting::VoteAmounts` (r:1 w:1)
+	/// Proof: `Bullposting::VoteAmounts` (`max_values`: None, `max_size`: Some(1650), added: 4125, mode: `MaxEncodedLen`)
+	fn migrate_v4_to_v5_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `282`
+		//  Estimated: `6134`
+		// Minimum execution time: 18_904_000 picoseconds.
+		Weight::from_parts(19_611_000, 6134)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 }
@@ -42,7 +42,7 @@ use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Block, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
+	AccountId, Aura, Balance, Block, BlockNumber, Executive, Grandpa, InherentDataExt, Nonce, Runtime,
 	RuntimeCall, RuntimeGenesisConfig, SessionKeys, System, TransactionPayment, VERSION,
 };
 
@@ -214,6 +214,27 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(3)]
+	impl pallet_bullposting_runtime_api::BullpostingApi<
+		Block,
+		pallet_bullposting::Attestation<Runtime>,
+		pallet_bullposting::PostOutcome<Runtime>,
+		AccountId,
+		pallet_bullposting::VoterRecord<BlockNumber>,
+	> for Runtime {
+		fn attestation(id: [u8; 32]) -> Option<pallet_bullposting::Attestation<Runtime>> {
+			pallet_bullposting::Attestations::<Runtime>::get(id)
+		}
+
+		fn outcome(id: [u8; 32]) -> Option<pallet_bullposting::PostOutcome<Runtime>> {
+			pallet_bullposting::PostOutcomes::<Runtime>::get(id)
+		}
+
+		fn voter_record(who: AccountId) -> pallet_bullposting::VoterRecord<BlockNumber> {
+			pallet_bullposting::VoterRecords::<Runtime>::get(who)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -26,23 +26,32 @@
 // Substrate and Polkadot dependencies
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf},
+	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, SortedMembers, VariantCountOf},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
 	},
 };
-use frame_system::limits::{BlockLength, BlockWeights};
+use alloc::vec::Vec;
+use frame_system::{
+	limits::{BlockLength, BlockWeights},
+	EnsureSigned, EnsureSignedBy,
+};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{
+	traits::{One, Verify},
+	transaction_validity::TransactionPriority,
+	Perbill, Percent,
+};
 use sp_version::RuntimeVersion;
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, MultiBlockMigrations, Nonce,
+	PalletInfo, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason,
+	RuntimeOrigin, RuntimeTask, Signature, System, UncheckedExtrinsic, DAYS,
+	EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -68,12 +77,50 @@ parameter_types! {
 	pub const VotingPeriod: BlockNumber = 20;
     pub const BondMinimum: u32 = 500;
 	pub const VoteMinimum: u32 = 500;
-    pub const MaxVoters: u32 = 10000;
     pub const StorageRent: u32 = 1000;
     pub const MaxUrlLength: u32 = 2000;
 	pub const UnfreezeLimit: u32 = 1000;
+	pub const ReportDeposit: u32 = 100;
+	pub const AppealPeriod: BlockNumber = 10;
+	pub const AppealVotingPeriod: BlockNumber = 40; // appeal rounds last twice as long as the first round
+	pub const AppealVoteMinimum: u32 = 1000;
+	pub const AppealBondCoefficient: u32 = 200; // appealing a post bonded with 500 tokens takes a bond of at least 1000
+	pub const MaxAppeals: u32 = 1;
+	pub const MaxBatchSize: u32 = 50;
+	pub const MaxActivePosts: u32 = 10;
+	pub const MaxVotesPerAccount: u32 = 100; // an account can have 100 votes open at once
+	pub const CooldownPeriod: BlockNumber = 5; // one post every 30 seconds
+	pub const SelfVotePolicy: pallet_bullposting::SelfVotePolicy = pallet_bullposting::SelfVotePolicy::Forbid;
+	pub const KeeperReward: u32 = 100; // paid twice per post out of the 1000 token `StorageRent`
+	pub const BullpostingUnsignedPriority: TransactionPriority = TransactionPriority::MAX / 2;
+	pub const BullpostingUnsignedLongevity: BlockNumber = 5; // the offchain worker retries a post every 30 seconds
+	pub const AttestUrls: bool = true; // only has an effect on nodes running with `--enable-offchain-indexing`
+	pub const MaxTitleLength: u32 = 256;
+	pub const MaxAttesters: u32 = 16;
+	pub const OutcomeRetention: BlockNumber = 30 * DAYS; // outcomes can be queried for a month after a post is ended
+	pub const Parimutuel: bool = false; // true = votes are staked and the losing side pays the winning side
+	pub const ParimutuelFee: Percent = Percent::from_percent(5); // burned from the losing side's stakes in parimutuel mode
+	pub const AccuracyWeight: Percent = Percent::from_percent(0); // 50 = votes count between 0.5x and 1.5x by the voter's record
+	/// The members of the example permissioned board, see [`EnsureBoardMember`]. Empty unless set
+	/// by `sudo` with `system.set_storage`.
+	pub storage BoardMembers: Vec<AccountId> = Vec::new();
+}
+
+/// The sorted [`BoardMembers`].
+pub struct BoardMembership;
+
+impl SortedMembers<AccountId> for BoardMembership {
+	fn sorted_members() -> Vec<AccountId> {
+		let mut members = BoardMembers::get();
+		members.sort();
+		members
+	}
 }
 
+/// An example `SubmitOrigin` and `VoteOrigin` for permissioned boards, only accepting signed
+/// origins of the [`BoardMembers`]. Swap it in for `EnsureSigned` to restrict posting and voting.
+pub type EnsureBoardMember = EnsureSignedBy<BoardMembership, AccountId>;
+
 /// The default types are being injected by [`derive_impl`](`frame_support::derive_impl`) from
 /// [`SoloChainDefaultConfig`](`struct@frame_system::config_preludes::SolochainDefaultConfig`),
 /// but overridden as needed.
@@ -102,6 +149,8 @@ impl frame_system::Config for Runtime {
 	/// This is used as an identifier of the chain. 42 is the generic substrate prefix.
 	type SS58Prefix = SS58Prefix;
 	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	/// Steps through the multi-block migrations, see [`MultiBlockMigrations`].
+	type MultiBlockMigrator = MultiBlockMigrations;
 }
 
 impl pallet_aura::Config for Runtime {
@@ -165,6 +214,32 @@ impl pallet_transaction_payment::Config for Runtime {
 	type WeightInfo = pallet_transaction_payment::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	/// Multi-block migrations may use up to 80% of a block.
+	pub MbmServiceWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_migrations::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Migrations = (
+		pallet_bullposting::migrations::v1::LazyMigrationV0ToV1<Runtime>,
+		pallet_bullposting::migrations::v2::LazyMigrationV1ToV2<Runtime>,
+		pallet_bullposting::migrations::v3::LazyMigrationV2ToV3<Runtime>,
+		pallet_bullposting::migrations::v4::LazyMigrationV3ToV4<Runtime>,
+		pallet_bullposting::migrations::v5::LazyMigrationV4ToV5<Runtime>,
+	);
+	// Benchmarks need mocked migrations to guarantee that they succeed.
+	#[cfg(feature = "runtime-benchmarks")]
+	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
+	type CursorMaxLen = ConstU32<65_536>;
+	type IdentifierMaxLen = ConstU32<256>;
+	type MigrationStatusHandler = ();
+	type FailedMigrationHandler = frame_support::migrations::FreezeChainOnFailedMigration;
+	type MaxServiceWeight = MbmServiceWeight;
+	type WeightInfo = pallet_migrations::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -189,7 +264,57 @@ impl pallet_bullposting::Config for Runtime {
     type MaxUrlLength = MaxUrlLength;
     type BondMinimum = BondMinimum;
 	type VoteMinimum = VoteMinimum;
-	type MaxVoters = MaxVoters;
 	type StorageRent = StorageRent;
 	type UnfreezeLimit = UnfreezeLimit;
+	type ModeratorOrigin = frame_system::EnsureRoot<AccountId>;
+	type SubmitOrigin = EnsureSigned<AccountId>;
+	type VoteOrigin = EnsureSigned<AccountId>;
+	type PauseOrigin = frame_system::EnsureRoot<AccountId>;
+	type ReportDeposit = ReportDeposit;
+	type AppealPeriod = AppealPeriod;
+	type AppealVotingPeriod = AppealVotingPeriod;
+	type AppealVoteMinimum = AppealVoteMinimum;
+	type AppealBondCoefficient = AppealBondCoefficient;
+	type MaxAppeals = MaxAppeals;
+	type MaxBatchSize = MaxBatchSize;
+	type MaxActivePosts = MaxActivePosts;
+	type MaxVotesPerAccount = MaxVotesPerAccount;
+	type CooldownPeriod = CooldownPeriod;
+	type SelfVotePolicy = SelfVotePolicy;
+	type KeeperReward = KeeperReward;
+	type UnsignedPriority = BullpostingUnsignedPriority;
+	type UnsignedLongevity = BullpostingUnsignedLongevity;
+	type AttestUrls = AttestUrls;
+	type MaxTitleLength = MaxTitleLength;
+	type AuthorityId = pallet_bullposting::crypto::AttesterAuthId;
+	type MaxAttesters = MaxAttesters;
+	type OutcomeRetention = OutcomeRetention;
+	type Parimutuel = Parimutuel;
+	type ParimutuelFee = ParimutuelFee;
+	type AccuracyWeight = AccuracyWeight;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	type Extrinsic = UncheckedExtrinsic;
+	type RuntimeCall = RuntimeCall;
+}
+
+/// Lets the bullposting offchain worker sign its `attest_url` payloads with account keys.
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+/// Lets the bullposting offchain worker submit its unsigned `auto_end_post`,
+/// `auto_resolve_voting` and `attest_url` transactions.
+impl<LocalCall> frame_system::offchain::CreateInherent<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_inherent(call: RuntimeCall) -> UncheckedExtrinsic {
+		UncheckedExtrinsic::new_bare(call)
+	}
 }
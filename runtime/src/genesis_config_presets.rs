@@ -15,11 +15,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AccountId, BalancesConfig, RuntimeGenesisConfig, SudoConfig};
-use alloc::{vec, vec::Vec};
+use crate::{AccountId, Balance, BalancesConfig, BullpostingConfig, RuntimeGenesisConfig, SudoConfig};
+use alloc::{format, vec, vec::Vec};
+use pallet_bullposting::Direction;
 use serde_json::Value;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
+use sp_core::{
+	crypto::{Derive, DeriveJunction},
+	sr25519, Pair,
+};
 use sp_genesis_builder::{self, PresetId};
 use sp_keyring::AccountKeyring;
 
@@ -28,15 +33,26 @@ fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	endowed_accounts: Vec<AccountId>,
 	root: AccountId,
+	bullposting: BullpostingConfig,
+) -> Value {
+	staging_config_genesis(
+		initial_authorities,
+		endowed_accounts.into_iter().map(|k| (k, 1u128 << 60)).collect::<Vec<_>>(),
+		root,
+		bullposting,
+	)
+}
+
+/// Returns the genesis config patch of a network with the given authorities, endowments, sudo key and
+/// pallet-bullposting genesis. Used by the node for staging chain specs read from a file.
+pub fn staging_config_genesis(
+	initial_authorities: Vec<(AuraId, GrandpaId)>,
+	endowments: Vec<(AccountId, Balance)>,
+	root: AccountId,
+	bullposting: BullpostingConfig,
 ) -> Value {
 	let config = RuntimeGenesisConfig {
-		balances: BalancesConfig {
-			balances: endowed_accounts
-				.iter()
-				.cloned()
-				.map(|k| (k, 1u128 << 60))
-				.collect::<Vec<_>>(),
-		},
+		balances: BalancesConfig { balances: endowments },
 		aura: pallet_aura::GenesisConfig {
 			authorities: initial_authorities.iter().map(|x| (x.0.clone())).collect::<Vec<_>>(),
 		},
@@ -45,12 +61,62 @@ fn testnet_genesis(
 			..Default::default()
 		},
 		sudo: SudoConfig { key: Some(root) },
+		bullposting,
 		..Default::default()
 	};
 
 	serde_json::to_value(config).expect("Could not build genesis config.")
 }
 
+/// The preset for stress testing the pallet-bullposting, see [`load_test_config_genesis`].
+pub const LOAD_TEST_RUNTIME_PRESET: &str = "bullposting-load";
+
+/// Number of `//load/<index>` accounts funded by the load-test preset.
+pub const LOAD_TEST_ACCOUNTS: u32 = 2_000;
+
+/// Number of open posts seeded by the load-test preset, each voted on by every other load-test account.
+pub const LOAD_TEST_POSTS: u32 = 10;
+
+/// URL of the load-test post that nobody has voted on, for the node's `benchmark extrinsic` vote builder.
+pub const LOAD_TEST_UNVOTED_URL: &str = "https://example.com/load/unvoted";
+
+/// Returns the URL of the `index`th post seeded by the load-test preset.
+pub fn load_test_url(index: u32) -> Vec<u8> {
+	format!("https://example.com/load/{index}").into_bytes()
+}
+
+// Returns a few sample posts and votes, so that test networks do not start empty.
+fn sample_content() -> BullpostingConfig {
+	let alice = AccountKeyring::Alice.to_account_id();
+	let bob = AccountKeyring::Bob.to_account_id();
+	let alice_stash = AccountKeyring::AliceStash.to_account_id();
+	let bob_stash = AccountKeyring::BobStash.to_account_id();
+
+	let bullish = b"https://polkadot.com/".to_vec();
+	let bearish = b"https://example.com/get-rich-quick".to_vec();
+	let undecided = b"https://github.com/paritytech/polkadot-sdk".to_vec();
+
+	BullpostingConfig {
+		// Staggered voting periods, so that posts can be ended at different times
+		posts: vec![
+			(alice.clone(), bullish.clone(), 1_000, 20),
+			(bob.clone(), bearish.clone(), 1_000, 40),
+			(alice.clone(), undecided.clone(), 1_000, 100),
+		],
+		// Submitters do not vote on their own posts, as the runtime's `SelfVotePolicy` forbids it
+		votes: vec![
+			(bob.clone(), bullish.clone(), 2_000, Direction::Bullish),
+			(alice_stash.clone(), bullish, 1_000, Direction::Bearish),
+			(alice, bearish.clone(), 2_000, Direction::Bearish),
+			(bob_stash.clone(), bearish, 500, Direction::Bullish),
+			(bob, undecided.clone(), 1_000, Direction::Bullish),
+			(bob_stash, undecided, 1_000, Direction::Bearish),
+		],
+		// `--alice` puts Alice's attester key in her node's keystore
+		attesters: vec![AccountKeyring::Alice.to_account_id()],
+	}
+}
+
 /// Return the development genesis config.
 pub fn development_config_genesis() -> Value {
 	testnet_genesis(
@@ -65,6 +131,78 @@ pub fn development_config_genesis() -> Value {
 			AccountKeyring::BobStash.to_account_id(),
 		],
 		sp_keyring::AccountKeyring::Alice.to_account_id(),
+		sample_content(),
+	)
+}
+
+/// Returns the `//load/0` to `//load/<count - 1>` accounts, deterministically derived from the dev phrase.
+pub fn load_test_accounts(count: u32) -> Vec<AccountId> {
+	// Only the hard junction needs the secret key, the numbered accounts are soft derived from the public key
+	let root = sr25519::Pair::from_string("//load", None).expect("static values are valid; qed").public();
+	(0..count)
+		.map(|index| {
+			// Numeric junctions of a secret URI are encoded as u64
+			root.derive(core::iter::once(DeriveJunction::soft(u64::from(index))))
+				.expect("soft junctions can always be derived; qed")
+				.into()
+		})
+		.collect()
+}
+
+/// Return the load-test genesis config preset.
+///
+/// Funds [`LOAD_TEST_ACCOUNTS`] accounts on top of the development ones. The first [`LOAD_TEST_POSTS`] of them
+/// submit a post each, and all the others vote on every post, so each post has more voters than `UnfreezeLimit`
+/// and needs several `try_resolve_voting` calls. The posts end a few blocks apart. Alice also submits a post at
+/// [`LOAD_TEST_UNVOTED_URL`] that stays open for a day and has no votes.
+pub fn load_test_config_genesis() -> Value {
+	let accounts = load_test_accounts(LOAD_TEST_ACCOUNTS);
+	let (submitters, voters) = accounts.split_at(LOAD_TEST_POSTS as usize);
+	let url = |index: usize| load_test_url(index as u32);
+
+	let bullposting = BullpostingConfig {
+		posts: submitters
+			.iter()
+			.enumerate()
+			.map(|(index, submitter)| (submitter.clone(), url(index), 1_000, 20 + 10 * index as u32))
+			.chain(core::iter::once((
+				AccountKeyring::Alice.to_account_id(),
+				LOAD_TEST_UNVOTED_URL.as_bytes().to_vec(),
+				1_000,
+				crate::DAYS,
+			)))
+			.collect(),
+		votes: (0..submitters.len())
+			.flat_map(|post| {
+				voters.iter().enumerate().map(move |(index, voter)| {
+					let direction = match (post + index) % 3 {
+						0 => Direction::Bullish,
+						1 => Direction::Bearish,
+						_ => Direction::Tie,
+					};
+					(voter.clone(), url(post), 500 + (index % 100) as u128 * 10, direction)
+				})
+			})
+			.collect(),
+		attesters: Vec::new(),
+	};
+
+	testnet_genesis(
+		vec![(
+			sp_keyring::Sr25519Keyring::Alice.public().into(),
+			sp_keyring::Ed25519Keyring::Alice.public().into(),
+		)],
+		[
+			AccountKeyring::Alice.to_account_id(),
+			AccountKeyring::Bob.to_account_id(),
+			AccountKeyring::AliceStash.to_account_id(),
+			AccountKeyring::BobStash.to_account_id(),
+		]
+		.into_iter()
+		.chain(accounts.iter().cloned())
+		.collect(),
+		AccountKeyring::Alice.to_account_id(),
+		bullposting,
 	)
 }
 
@@ -86,6 +224,7 @@ pub fn local_config_genesis() -> Value {
 			.map(|v| v.to_account_id())
 			.collect::<Vec<_>>(),
 		AccountKeyring::Alice.to_account_id(),
+		sample_content(),
 	)
 }
 
@@ -94,6 +233,7 @@ pub fn get_preset(id: &PresetId) -> Option<Vec<u8>> {
 	let patch = match id.as_ref() {
 		sp_genesis_builder::DEV_RUNTIME_PRESET => development_config_genesis(),
 		sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET => local_config_genesis(),
+		LOAD_TEST_RUNTIME_PRESET => load_test_config_genesis(),
 		_ => return None,
 	};
 	Some(
@@ -108,5 +248,6 @@ pub fn preset_names() -> Vec<PresetId> {
 	vec![
 		PresetId::from(sp_genesis_builder::DEV_RUNTIME_PRESET),
 		PresetId::from(sp_genesis_builder::LOCAL_TESTNET_RUNTIME_PRESET),
+		PresetId::from(LOAD_TEST_RUNTIME_PRESET),
 	]
 }
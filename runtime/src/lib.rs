@@ -21,6 +21,7 @@ use sp_version::RuntimeVersion;
 
 pub use frame_system::Call as SystemCall;
 pub use pallet_balances::Call as BalancesCall;
+pub use pallet_bullposting::Call as BullpostingCall;
 pub use pallet_timestamp::Call as TimestampCall;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
@@ -54,6 +55,8 @@ impl_opaque_keys! {
 	pub struct SessionKeys {
 		pub aura: Aura,
 		pub grandpa: Grandpa,
+		/// The key the bullposting offchain worker signs URL attestations with.
+		pub bullposting: pallet_bullposting::crypto::Public,
 	}
 }
 
@@ -69,10 +72,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	spec_version: 116,
 	impl_version: 1,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 3,
 	system_version: 1,
 };
 
@@ -168,7 +171,8 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 
 /// All migrations of the runtime, aside from the ones declared in the pallets.
 ///
-/// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
+/// This can be a tuple of types, each implementing `OnRuntimeUpgrade`. Multi-block migrations are
+/// set in the `pallet_migrations` config instead, and run after these.
 #[allow(unused_parens)]
 type Migrations = ();
 
@@ -223,6 +227,9 @@ mod runtime {
     // Include the custom logic from the pallet-bullposting in the runtime.
     #[runtime::pallet_index(7)]
     pub type Bullposting = pallet_bullposting;
+
+	#[runtime::pallet_index(8)]
+	pub type MultiBlockMigrations = pallet_migrations;
 }
 
 